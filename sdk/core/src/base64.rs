@@ -0,0 +1,187 @@
+//! Encode and decode base64 used for binary model fields.
+//!
+//! Decoding accepts input with or without padding. Each encoding has a module that can be used
+//! with `#[serde(with = "...")]` on `Vec<u8>` fields, along with an `option` module for `Option<Vec<u8>>` fields:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct JsonWebKey {
+//!     #[serde(with = "azure_core::base64::url_safe_no_pad")]
+//!     n: Vec<u8>,
+//!     #[serde(default, with = "azure_core::base64::url_safe_no_pad::option")]
+//!     x: Option<Vec<u8>>,
+//! }
+//! ```
+
+use crate::error::{ErrorKind, ResultExt};
+use ::base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+
+const STANDARD: GeneralPurpose = engine(&alphabet::STANDARD, true);
+const STANDARD_NO_PAD: GeneralPurpose = engine(&alphabet::STANDARD, false);
+const URL_SAFE: GeneralPurpose = engine(&alphabet::URL_SAFE, true);
+const URL_SAFE_NO_PAD: GeneralPurpose = engine(&alphabet::URL_SAFE, false);
+
+const fn engine(alphabet: &alphabet::Alphabet, padding: bool) -> GeneralPurpose {
+    GeneralPurpose::new(
+        alphabet,
+        GeneralPurposeConfig::new()
+            .with_encode_padding(padding)
+            .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+    )
+}
+
+/// Encode bytes using the standard alphabet with padding.
+pub fn encode(bytes: impl AsRef<[u8]>) -> String {
+    STANDARD.encode(bytes)
+}
+
+/// Decode a string using the standard alphabet.
+pub fn decode(s: impl AsRef<[u8]>) -> crate::Result<Vec<u8>> {
+    decode_with(&STANDARD, s)
+}
+
+/// Encode bytes using the URL-safe alphabet without padding, as used by JSON Web Keys.
+pub fn encode_url_safe(bytes: impl AsRef<[u8]>) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Decode a string using the URL-safe alphabet.
+pub fn decode_url_safe(s: impl AsRef<[u8]>) -> crate::Result<Vec<u8>> {
+    decode_with(&URL_SAFE_NO_PAD, s)
+}
+
+fn decode_with(engine: &GeneralPurpose, s: impl AsRef<[u8]>) -> crate::Result<Vec<u8>> {
+    engine
+        .decode(s)
+        .context(ErrorKind::DataConversion, "invalid base64")
+}
+
+macro_rules! serde_module {
+    ($(#[$doc:meta])* $name:ident, $engine:ident) => {
+        $(#[$doc])*
+        pub mod $name {
+            use super::*;
+            use serde::{de, Deserialize, Deserializer, Serializer};
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                decode_with(&$engine, s).map_err(de::Error::custom)
+            }
+
+            pub fn serialize<S, T>(bytes: T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+                T: AsRef<[u8]>,
+            {
+                serializer.serialize_str(&$engine.encode(bytes))
+            }
+
+            /// Serialize and deserialize optional bytes.
+            pub mod option {
+                use super::*;
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Option::<String>::deserialize(deserializer)?
+                        .map(|s| decode_with(&$engine, s).map_err(de::Error::custom))
+                        .transpose()
+                }
+
+                pub fn serialize<S, T>(bytes: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                    T: AsRef<[u8]>,
+                {
+                    match bytes {
+                        Some(bytes) => serializer.serialize_some(&$engine.encode(bytes)),
+                        None => serializer.serialize_none(),
+                    }
+                }
+            }
+        }
+    };
+}
+
+serde_module!(
+    /// Serialize and deserialize bytes using the standard alphabet with padding.
+    standard,
+    STANDARD
+);
+serde_module!(
+    /// Serialize and deserialize bytes using the standard alphabet without padding.
+    standard_no_pad,
+    STANDARD_NO_PAD
+);
+serde_module!(
+    /// Serialize and deserialize bytes using the URL-safe alphabet with padding.
+    url_safe,
+    URL_SAFE
+);
+serde_module!(
+    /// Serialize and deserialize bytes using the URL-safe alphabet without padding, as used by JSON Web Keys.
+    url_safe_no_pad,
+    URL_SAFE_NO_PAD
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn encode_decode() {
+        let bytes = [0xfb, 0xff, 0xbf, 0x01];
+        assert_eq!(encode(bytes), "+/+/AQ==");
+        assert_eq!(encode_url_safe(bytes), "-_-_AQ");
+
+        assert_eq!(decode("+/+/AQ==").expect("padded"), bytes);
+        assert_eq!(decode("+/+/AQ").expect("unpadded"), bytes);
+        assert_eq!(decode_url_safe("-_-_AQ==").expect("padded"), bytes);
+        assert_eq!(decode_url_safe("-_-_AQ").expect("unpadded"), bytes);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let error = decode_url_safe("+/+/AQ").expect_err("wrong alphabet");
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Model {
+        #[serde(with = "standard")]
+        cer: Vec<u8>,
+        #[serde(with = "url_safe_no_pad")]
+        n: Vec<u8>,
+        #[serde(default, with = "url_safe_no_pad::option")]
+        x: Option<Vec<u8>>,
+        #[serde(default, with = "standard_no_pad::option")]
+        y: Option<Vec<u8>>,
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let json = r#"{"cer":"+/+/AQ==","n":"-_-_AQ","x":"AQI","y":null}"#;
+        let model: Model = serde_json::from_str(json).expect("deserialize");
+        assert_eq!(
+            model,
+            Model {
+                cer: vec![0xfb, 0xff, 0xbf, 0x01],
+                n: vec![0xfb, 0xff, 0xbf, 0x01],
+                x: Some(vec![0x01, 0x02]),
+                y: None,
+            }
+        );
+        assert_eq!(serde_json::to_string(&model).expect("serialize"), json);
+    }
+}
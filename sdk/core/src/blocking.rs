@@ -0,0 +1,91 @@
+//! Call asynchronous client methods from synchronous code.
+//!
+//! Futures run on a runtime shared by all blocking clients, so synchronous applications do not need to create one.
+//! Do not call these functions from within an asynchronous runtime; they will panic.
+
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use std::{future::Future, sync::OnceLock};
+use tokio::runtime::{Builder, Runtime};
+
+/// Runs a future to completion, blocking the current thread.
+///
+/// Use this to call asynchronous APIs from synchronous code e.g., to read a [`Response`](crate::Response):
+///
+/// ```no_run
+/// # fn read(response: azure_core::Response) -> azure_core::Result<()> {
+/// let value: serde_json::Value = azure_core::blocking::block_on(response.json())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called from within an asynchronous runtime.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("azure-core-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to create blocking runtime")
+    })
+}
+
+/// An [`Iterator`] of items from a paged list operation, requesting pages as needed.
+pub struct Pager<T>(crate::Pager<T>);
+
+impl<T> Pager<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Gets the [`ContinuationToken`](crate::ContinuationToken) of the next page, or `None` if there are no more pages.
+    pub fn continuation_token(&self) -> Option<crate::ContinuationToken> {
+        self.0.continuation_token()
+    }
+}
+
+impl<T> From<crate::Pager<T>> for Pager<T> {
+    fn from(pager: crate::Pager<T>) -> Self {
+        Self(pager)
+    }
+}
+
+impl<T> Iterator for Pager<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.0.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::pager, Context, Request, TransportOptions, Url};
+
+    #[test]
+    fn iterates_pages() {
+        let pager: Pager<i32> = pager(None).into();
+        let items: Vec<i32> = pager.collect::<crate::Result<_>>().expect("items");
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn sends_over_transport() {
+        let transport = TransportOptions::new("ok");
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+        let response =
+            block_on(transport.send(&mut Context::default(), &mut request)).expect("response");
+        assert_eq!(block_on(response.into_body().collect()).unwrap(), "ok");
+    }
+}
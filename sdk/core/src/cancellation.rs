@@ -0,0 +1,154 @@
+use crate::{
+    context::Context,
+    error::{Error, ErrorKind},
+};
+use futures::future::{select, Either};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Poll, Waker},
+};
+
+/// Cancels operations, such as waiting on a [`Poller`](crate::Poller), that were passed a [`Context`] containing this token.
+///
+/// Clones share the same state, so a clone inserted into a [`Context`] can be cancelled from another task.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_id: AtomicU64,
+    /// The waker of each pending [`Cancelled`] future, removed when the future is dropped.
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel any operations using this token.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        let wakers = std::mem::take(&mut *self.0.wakers.lock().expect("lock wakers"));
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Completes when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            id: self.0.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+/// A [`Future`] that completes when a [`CancellationToken`] is cancelled.
+#[derive(Debug)]
+pub struct Cancelled {
+    token: CancellationToken,
+    id: u64,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let token = &self.token;
+        if token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        // Replace the waker registered by a previous poll, if any, so that at most one is kept per future.
+        let mut wakers = token.0.wakers.lock().expect("lock wakers");
+        match wakers.get_mut(&self.id) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => {
+                wakers.insert(self.id, cx.waker().clone());
+            }
+        }
+        drop(wakers);
+
+        // Check again in case the token was cancelled before the waker was registered.
+        match token.is_cancelled() {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Ok(mut wakers) = self.token.0.wakers.lock() {
+            wakers.remove(&self.id);
+        }
+    }
+}
+
+/// Run a future until it completes or the [`CancellationToken`] in the [`Context`], if any, is cancelled.
+pub(crate) async fn cancellable<F, T>(ctx: &Context, future: F) -> crate::Result<T>
+where
+    F: Future<Output = crate::Result<T>>,
+{
+    let Some(token) = ctx.value::<CancellationToken>() else {
+        return future.await;
+    };
+    if token.is_cancelled() {
+        return Err(Error::message(ErrorKind::Cancelled, "operation cancelled"));
+    }
+
+    match select(pin!(future), token.cancelled()).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(Error::message(ErrorKind::Cancelled, "operation cancelled")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::task::{waker, ArcWake};
+
+    struct CountWaker(Arc<AtomicU64>);
+
+    impl ArcWake for CountWaker {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn keeps_one_waker_per_future() {
+        let token = CancellationToken::new();
+        let count = Arc::new(AtomicU64::new(0));
+        let mut cancelled = token.cancelled();
+
+        for _ in 0..10 {
+            // Each poll uses a different waker, as when a future moves between tasks.
+            let waker = waker(Arc::new(CountWaker(count.clone())));
+            let mut cx = std::task::Context::from_waker(&waker);
+            assert!(Pin::new(&mut cancelled).poll(&mut cx).is_pending());
+
+            let mut dropped = token.cancelled();
+            assert!(Pin::new(&mut dropped).poll(&mut cx).is_pending());
+        }
+        assert_eq!(token.0.wakers.lock().unwrap().len(), 1);
+
+        token.cancel();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        drop(cancelled);
+        assert!(token.0.wakers.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Authority hosts, audiences, and endpoint suffixes of Azure clouds.
+//!
+//! Set the cloud for a client using [`ClientOptionsBuilder::with_cloud`](crate::ClientOptionsBuilder::with_cloud).
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use url::Url;
+
+/// Service name of Azure Key Vault.
+pub const KEY_VAULT: &str = "key_vault";
+/// Service name of Azure Resource Manager.
+pub const RESOURCE_MANAGER: &str = "resource_manager";
+/// Service name of Azure Storage.
+pub const STORAGE: &str = "storage";
+
+/// The Azure cloud that credentials authenticate with and clients connect to.
+///
+/// When deserialized, presets are named `azure_public`, `azure_china`, or `azure_us_government`,
+/// and a custom cloud is a `custom` table of [`CustomCloudConfiguration`] fields.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloudConfiguration {
+    /// Azure public cloud.
+    #[default]
+    AzurePublic,
+    /// Azure operated by 21Vianet in China.
+    AzureChina,
+    /// Azure US Government.
+    #[serde(rename = "azure_us_government")]
+    AzureUSGovernment,
+    /// Any other cloud e.g., Azure Stack.
+    Custom(CustomCloudConfiguration),
+}
+
+/// Settings of a cloud without a preset e.g., Azure Stack.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CustomCloudConfiguration {
+    /// The Microsoft Entra ID authority host e.g., `https://login.microsoftonline.com/`.
+    pub authority_host: Url,
+
+    /// Audiences of each service e.g., `https://vault.azure.net` for [`KEY_VAULT`].
+    #[serde(default)]
+    pub audiences: HashMap<String, String>,
+
+    /// DNS suffixes of service endpoints e.g., `vault.azure.net` for [`KEY_VAULT`].
+    #[serde(default)]
+    pub endpoint_suffixes: HashMap<String, String>,
+}
+
+impl CustomCloudConfiguration {
+    pub fn new(authority_host: Url) -> Self {
+        Self {
+            authority_host,
+            audiences: HashMap::new(),
+            endpoint_suffixes: HashMap::new(),
+        }
+    }
+}
+
+impl From<CustomCloudConfiguration> for CloudConfiguration {
+    fn from(custom: CustomCloudConfiguration) -> Self {
+        Self::Custom(custom)
+    }
+}
+
+struct Preset {
+    authority_host: &'static str,
+    audiences: &'static [(&'static str, &'static str)],
+    endpoint_suffixes: &'static [(&'static str, &'static str)],
+}
+
+const AZURE_PUBLIC: Preset = Preset {
+    authority_host: "https://login.microsoftonline.com/",
+    audiences: &[
+        (KEY_VAULT, "https://vault.azure.net"),
+        (RESOURCE_MANAGER, "https://management.azure.com"),
+        (STORAGE, "https://storage.azure.com"),
+    ],
+    endpoint_suffixes: &[
+        (KEY_VAULT, "vault.azure.net"),
+        (STORAGE, "core.windows.net"),
+    ],
+};
+
+const AZURE_CHINA: Preset = Preset {
+    authority_host: "https://login.chinacloudapi.cn/",
+    audiences: &[
+        (KEY_VAULT, "https://vault.azure.cn"),
+        (RESOURCE_MANAGER, "https://management.chinacloudapi.cn"),
+        (STORAGE, "https://storage.azure.com"),
+    ],
+    endpoint_suffixes: &[
+        (KEY_VAULT, "vault.azure.cn"),
+        (STORAGE, "core.chinacloudapi.cn"),
+    ],
+};
+
+const AZURE_US_GOVERNMENT: Preset = Preset {
+    authority_host: "https://login.microsoftonline.us/",
+    audiences: &[
+        (KEY_VAULT, "https://vault.usgovcloudapi.net"),
+        (RESOURCE_MANAGER, "https://management.usgovcloudapi.net"),
+        (STORAGE, "https://storage.azure.com"),
+    ],
+    endpoint_suffixes: &[
+        (KEY_VAULT, "vault.usgovcloudapi.net"),
+        (STORAGE, "core.usgovcloudapi.net"),
+    ],
+};
+
+impl CloudConfiguration {
+    /// The Microsoft Entra ID authority host that credentials request tokens from.
+    pub fn authority_host(&self) -> &str {
+        match self {
+            Self::Custom(custom) => custom.authority_host.as_str(),
+            _ => self.preset().authority_host,
+        }
+    }
+
+    /// The audience of `service` e.g., [`KEY_VAULT`], or `None` if the cloud does not define one.
+    pub fn audience(&self, service: &str) -> Option<&str> {
+        match self {
+            Self::Custom(custom) => custom.audiences.get(service).map(String::as_str),
+            _ => find(self.preset().audiences, service),
+        }
+    }
+
+    /// The DNS suffix of endpoints for `service` e.g., [`KEY_VAULT`], or `None` if the cloud does not define one.
+    pub fn endpoint_suffix(&self, service: &str) -> Option<&str> {
+        match self {
+            Self::Custom(custom) => custom.endpoint_suffixes.get(service).map(String::as_str),
+            _ => find(self.preset().endpoint_suffixes, service),
+        }
+    }
+
+    /// The scope to request tokens for `service`, or an error if the cloud does not define its audience.
+    pub fn scope(&self, service: &str) -> crate::Result<String> {
+        let audience = self.audience(service).ok_or_else(|| {
+            crate::Error::with_message(crate::ErrorKind::Other, || {
+                format!("cloud configuration does not define an audience for {service}")
+            })
+        })?;
+        Ok(format!("{}/.default", audience.trim_end_matches('/')))
+    }
+
+    fn preset(&self) -> &'static Preset {
+        match self {
+            Self::AzurePublic => &AZURE_PUBLIC,
+            Self::AzureChina => &AZURE_CHINA,
+            Self::AzureUSGovernment => &AZURE_US_GOVERNMENT,
+            Self::Custom(_) => unreachable!("custom clouds have no preset"),
+        }
+    }
+}
+
+fn find(entries: &'static [(&'static str, &'static str)], service: &str) -> Option<&'static str> {
+    entries
+        .iter()
+        .find(|(name, _)| *name == service)
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        let cloud = CloudConfiguration::default();
+        assert_eq!(cloud.authority_host(), "https://login.microsoftonline.com/");
+        assert_eq!(
+            cloud.scope(KEY_VAULT).unwrap(),
+            "https://vault.azure.net/.default"
+        );
+
+        let cloud = CloudConfiguration::AzureChina;
+        assert_eq!(cloud.authority_host(), "https://login.chinacloudapi.cn/");
+        assert_eq!(
+            cloud.scope(KEY_VAULT).unwrap(),
+            "https://vault.azure.cn/.default"
+        );
+        assert_eq!(
+            cloud.endpoint_suffix(STORAGE),
+            Some("core.chinacloudapi.cn")
+        );
+
+        let cloud = CloudConfiguration::AzureUSGovernment;
+        assert_eq!(
+            cloud.endpoint_suffix(KEY_VAULT),
+            Some("vault.usgovcloudapi.net")
+        );
+        assert_eq!(cloud.endpoint_suffix(RESOURCE_MANAGER), None);
+    }
+
+    #[test]
+    fn custom() {
+        let mut custom = CustomCloudConfiguration::new(
+            Url::parse("https://login.azurestack.local/adfs/").unwrap(),
+        );
+        custom.audiences.insert(
+            KEY_VAULT.to_string(),
+            "https://vault.azurestack.local/".to_string(),
+        );
+        let cloud: CloudConfiguration = custom.into();
+
+        assert_eq!(
+            cloud.authority_host(),
+            "https://login.azurestack.local/adfs/"
+        );
+        assert_eq!(
+            cloud.scope(KEY_VAULT).unwrap(),
+            "https://vault.azurestack.local/.default"
+        );
+        assert!(cloud.scope(STORAGE).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    date::{self, OffsetDateTime},
+    headers::{
+        AsHeaders, HeaderName, HeaderValue, IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+        IF_UNMODIFIED_SINCE,
+    },
+    Etag,
+};
+
+/// Conditions on the [`Etag`] of a resource for optimistic concurrency.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchConditions {
+    /// Perform the operation only if the resource's [`Etag`] matches.
+    pub if_match: Option<Etag>,
+    /// Perform the operation only if the resource's [`Etag`] does not match.
+    pub if_none_match: Option<Etag>,
+}
+
+impl MatchConditions {
+    pub fn if_match(etag: impl Into<Etag>) -> Self {
+        Self {
+            if_match: Some(etag.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn if_none_match(etag: impl Into<Etag>) -> Self {
+        Self {
+            if_none_match: Some(etag.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl AsHeaders for MatchConditions {
+    type Iter = std::vec::IntoIter<(HeaderName, HeaderValue)>;
+
+    fn as_headers(&self) -> Self::Iter {
+        let mut headers = Vec::new();
+        if let Some(etag) = &self.if_match {
+            headers.push((IF_MATCH, etag.to_string().into()));
+        }
+        if let Some(etag) = &self.if_none_match {
+            headers.push((IF_NONE_MATCH, etag.to_string().into()));
+        }
+        headers.into_iter()
+    }
+}
+
+/// Conditions on the [`Etag`] and modification time of a resource.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestConditions {
+    pub match_conditions: MatchConditions,
+    /// Perform the operation only if the resource was modified since the specified time.
+    pub if_modified_since: Option<OffsetDateTime>,
+    /// Perform the operation only if the resource was not modified since the specified time.
+    pub if_unmodified_since: Option<OffsetDateTime>,
+}
+
+impl From<MatchConditions> for RequestConditions {
+    fn from(match_conditions: MatchConditions) -> Self {
+        Self {
+            match_conditions,
+            ..Default::default()
+        }
+    }
+}
+
+impl AsHeaders for RequestConditions {
+    type Iter = std::vec::IntoIter<(HeaderName, HeaderValue)>;
+
+    fn as_headers(&self) -> Self::Iter {
+        let mut headers: Vec<_> = self.match_conditions.as_headers().collect();
+        if let Some(date) = &self.if_modified_since {
+            headers.push((IF_MODIFIED_SINCE, date::to_rfc1123(date).into()));
+        }
+        if let Some(date) = &self.if_unmodified_since {
+            headers.push((IF_UNMODIFIED_SINCE, date::to_rfc1123(date).into()));
+        }
+        headers.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Headers;
+    use time::macros::datetime;
+
+    #[test]
+    fn request_conditions_headers() {
+        let conditions = RequestConditions {
+            match_conditions: MatchConditions::if_match("\"0x8D\""),
+            if_unmodified_since: Some(datetime!(1994-11-06 08:49:37 UTC)),
+            ..Default::default()
+        };
+
+        let mut headers = Headers::new();
+        headers.add(conditions);
+
+        assert_eq!(headers.get_optional_str(&IF_MATCH), Some("\"0x8D\""));
+        assert_eq!(headers.get_optional_str(&IF_NONE_MATCH), None);
+        assert_eq!(headers.get_optional_str(&IF_MODIFIED_SINCE), None);
+        assert_eq!(
+            headers.get_optional_str(&IF_UNMODIFIED_SINCE),
+            Some("Sun, 06 Nov 1994 08:49:37 GMT")
+        );
+    }
+}
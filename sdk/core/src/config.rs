@@ -0,0 +1,497 @@
+//! Load options from configuration files and `AZURE_*` environment variables.
+//!
+//! Settings at the root of the configuration apply to all clients, and are overridden by settings in a client's section:
+//!
+//! ```toml
+//! cloud = "azure_us_government"
+//!
+//! [retry]
+//! mode = "exponential"
+//! max_retries = 5
+//! max_delay = "30s"
+//!
+//! [keyvault]
+//! api_version = "7.4"
+//!
+//! [keyvault.transport.connection]
+//! connect_timeout = "10s"
+//! ```
+//!
+//! Environment variables override the file, including its client sections. Their names start with `AZURE_`
+//! and separate nested keys with `__` e.g., `AZURE_RETRY__MAX_RETRIES=5` or `AZURE_KEYVAULT__API_VERSION=7.4`.
+//! Only `retry` and `transport` settings, and settings in the client's section e.g., `AZURE_KEYVAULT__CLOUD`,
+//! are read, so variables like `AZURE_CLIENT_SECRET` or `AZURE_CLOUD` used by other tools are ignored.
+//! Values are read as strings; numeric and boolean settings also accept strings.
+//!
+//! Durations are either seconds or strings with a unit of `ms`, `s`, `m`, or `h` e.g., `"1.5s"`.
+
+use crate::error::{Error, ErrorKind, ResultExt};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::{fmt, path::Path};
+
+/// Settings of [`ClientOptions`](crate::ClientOptions) read from environment variables outside of a client's section.
+const ENV_ROOT_KEYS: &[&str] = &["retry", "transport"];
+
+/// Configuration that [`ClientOptions`](crate::ClientOptions) and client options can be loaded from.
+///
+/// ```no_run
+/// use azure_core::{config::Config, ClientOptions};
+///
+/// # fn load() -> azure_core::Result<()> {
+/// let options: ClientOptions = Config::from_file("azure.json")?
+///     .with_env_vars()
+///     .options(Some("storage"))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Config {
+    value: Map<String, Value>,
+    env: Map<String, Value>,
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Environment variables may hold secrets, so only show which settings they set.
+        f.debug_struct("Config")
+            .field("value", &self.value)
+            .field("env", &self.env.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Config {
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let value: Map<String, Value> = serde_json::from_str(json)?;
+        Ok(Self {
+            value,
+            ..Default::default()
+        })
+    }
+
+    /// Parses TOML. Requires the `toml` feature.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> crate::Result<Self> {
+        let value =
+            toml::from_str(toml).map_err(|error| Error::new(ErrorKind::DataConversion, error))?;
+        Ok(Self {
+            value,
+            ..Default::default()
+        })
+    }
+
+    /// Reads a JSON file, or a TOML file if the path ends with `.toml`.
+    pub fn from_file(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).with_context(ErrorKind::Io, || {
+            format!("failed to read configuration from {}", path.display())
+        })?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents),
+            #[cfg(not(feature = "toml"))]
+            Some("toml") => Err(Error::message(
+                ErrorKind::DataConversion,
+                "TOML configuration requires the toml feature",
+            )),
+            _ => Self::from_json(&contents),
+        }
+    }
+
+    /// Overrides settings with `AZURE_*` environment variables.
+    ///
+    /// Only variables with nested keys e.g., `AZURE_RETRY__MAX_RETRIES` or `AZURE_KEYVAULT__API_VERSION` are read.
+    pub fn with_env_vars(&mut self) -> &mut Self {
+        self.with_vars(std::env::vars())
+    }
+
+    fn with_vars(&mut self, vars: impl IntoIterator<Item = (String, String)>) -> &mut Self {
+        for (name, value) in vars {
+            let Some(name) = name.strip_prefix("AZURE_") else {
+                continue;
+            };
+            let keys: Vec<String> = name.to_lowercase().split("__").map(String::from).collect();
+            if keys.len() < 2 || keys.iter().any(String::is_empty) {
+                continue;
+            }
+            insert(&mut self.env, &keys, Value::String(value));
+        }
+        self
+    }
+
+    /// Deserializes options from the root settings overridden by settings in `section`, if any.
+    ///
+    /// Settings are merged in order from the file's root, the file's section, `retry` and `transport`
+    /// environment variables, then the section's environment variables. Environment variables for other sections are ignored.
+    pub fn options<T: DeserializeOwned>(&self, section: Option<&str>) -> crate::Result<T> {
+        let mut value = self.value.clone();
+        if let Some(Value::Object(section)) = section.and_then(|section| self.value.get(section)) {
+            merge(&mut value, section.clone());
+        }
+        let env_root = self
+            .env
+            .iter()
+            .filter(|(key, _)| ENV_ROOT_KEYS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        merge(&mut value, env_root);
+        if let Some(Value::Object(section)) = section.and_then(|section| self.env.get(section)) {
+            merge(&mut value, section.clone());
+        }
+        serde_json::from_value(Value::Object(value)).with_context(ErrorKind::DataConversion, || {
+            match section {
+                Some(section) => format!("invalid configuration for {section}"),
+                None => "invalid configuration".to_string(),
+            }
+        })
+    }
+}
+
+fn insert(map: &mut Map<String, Value>, keys: &[String], value: Value) {
+    let (key, rest) = keys.split_first().expect("at least one key");
+    if rest.is_empty() {
+        map.insert(key.clone(), value);
+        return;
+    }
+
+    let entry = map
+        .entry(key.clone())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !entry.is_object() {
+        *entry = Value::Object(Map::new());
+    }
+    if let Value::Object(child) = entry {
+        insert(child, rest, value);
+    }
+}
+
+/// Recursively merges `other` into `map`, replacing values other than objects.
+fn merge(map: &mut Map<String, Value>, other: Map<String, Value>) {
+    for (key, value) in other {
+        match (map.get_mut(&key), value) {
+            (Some(Value::Object(existing)), Value::Object(value)) => merge(existing, value),
+            (_, value) => {
+                map.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Deserializes numbers or booleans from their JSON type or from strings e.g., set by environment variables.
+pub(crate) mod from_str {
+    use serde::{de, Deserialize, Deserializer};
+    use std::{fmt::Display, str::FromStr};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw<T> {
+        Value(T),
+        String(String),
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + FromStr,
+        T::Err: Display,
+    {
+        match Raw::<T>::deserialize(deserializer)? {
+            Raw::Value(value) => Ok(value),
+            Raw::String(value) => value
+                .trim()
+                .parse()
+                .map_err(|error| de::Error::custom(format!("invalid value {value:?}: {error}"))),
+        }
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de> + FromStr,
+            T::Err: Display,
+        {
+            #[derive(Deserialize)]
+            #[serde(bound = "T: Deserialize<'de> + FromStr, T::Err: Display")]
+            struct Wrapper<T>(#[serde(deserialize_with = "super::deserialize")] T);
+
+            Ok(Option::<Wrapper<T>>::deserialize(deserializer)?.map(|Wrapper(value)| value))
+        }
+    }
+}
+
+/// Deserializes durations from seconds or strings with a unit e.g., `"500ms"`.
+pub(crate) mod duration {
+    use serde::{de, Deserialize, Deserializer};
+    use std::time::Duration;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Seconds(f64),
+        String(String),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let seconds = match Raw::deserialize(deserializer)? {
+            Raw::Seconds(seconds) => seconds,
+            Raw::String(value) => parse(value.trim())
+                .ok_or_else(|| de::Error::custom(format!("invalid duration {value:?}")))?,
+        };
+        Duration::try_from_secs_f64(seconds).map_err(de::Error::custom)
+    }
+
+    fn parse(value: &str) -> Option<f64> {
+        let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+            (number, 0.001)
+        } else if let Some(number) = value.strip_suffix('s') {
+            (number, 1.0)
+        } else if let Some(number) = value.strip_suffix('m') {
+            (number, 60.0)
+        } else if let Some(number) = value.strip_suffix('h') {
+            (number, 3600.0)
+        } else {
+            (value, 1.0)
+        };
+        number
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|number| number * scale)
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(deserialize_with = "super::deserialize")] Duration);
+
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(duration)| duration))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::time::Duration;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default)]
+    struct TestOptions {
+        name: String,
+        #[serde(deserialize_with = "from_str::deserialize")]
+        count: u32,
+        #[serde(with = "duration::option")]
+        timeout: Option<Duration>,
+        nested: Nested,
+    }
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default)]
+    struct Nested {
+        #[serde(deserialize_with = "from_str::deserialize")]
+        enabled: bool,
+        version: String,
+    }
+
+    #[test]
+    fn merges_section_and_env_vars() {
+        let mut config = Config::from_json(
+            r#"{"count":1,"timeout":2.5,"nested":{"version":"1.0"},"test":{"name":"file","count":2}}"#,
+        )
+        .unwrap();
+        config.with_vars([
+            (
+                "AZURE_TEST__NESTED__ENABLED".to_string(),
+                "true".to_string(),
+            ),
+            ("AZURE_TEST__COUNT".to_string(), "3".to_string()),
+            ("AZURE_TEST__NESTED__VERSION".to_string(), "7.4".to_string()),
+            ("OTHER__COUNT".to_string(), "4".to_string()),
+        ]);
+
+        let options: TestOptions = config.options(Some("test")).unwrap();
+        assert_eq!(
+            options,
+            TestOptions {
+                name: "file".to_string(),
+                count: 3,
+                timeout: Some(Duration::from_millis(2500)),
+                nested: Nested {
+                    enabled: true,
+                    version: "7.4".to_string(),
+                },
+            }
+        );
+
+        let options: TestOptions = config.options(None).unwrap();
+        assert_eq!(options.count, 1);
+        assert_eq!(options.nested.version, "1.0");
+    }
+
+    #[test]
+    fn env_vars_keep_strings() {
+        let mut config =
+            Config::from_json(r#"{"count":1,"test":{"count":2,"name":"file"}}"#).unwrap();
+        config.with_vars([
+            ("AZURE_TEST__COUNT".to_string(), "5".to_string()),
+            ("AZURE_TEST__NAME".to_string(), "2024".to_string()),
+            (
+                "AZURE_TEST__NESTED__VERSION".to_string(),
+                "true".to_string(),
+            ),
+        ]);
+
+        let options: TestOptions = config.options(Some("test")).unwrap();
+        assert_eq!(options.count, 5);
+        assert_eq!(options.name, "2024");
+        assert_eq!(options.nested.version, "true");
+
+        config.with_vars([("AZURE_TEST__COUNT".to_string(), "many".to_string())]);
+        assert!(config.options::<TestOptions>(Some("test")).is_err());
+    }
+
+    #[test]
+    fn ignores_unrelated_env_vars() {
+        let mut config = Config::from_json(r#"{"test":{"name":"file"}}"#).unwrap();
+        config.with_vars([
+            ("AZURE_CLIENT_SECRET".to_string(), "secret".to_string()),
+            ("AZURE_CLOUD".to_string(), "AzureCloud".to_string()),
+            ("AZURE_TRANSPORT".to_string(), "grpc".to_string()),
+            ("AZURE_NAME".to_string(), "env".to_string()),
+            ("AZURE_OTHER__COUNT".to_string(), "many".to_string()),
+            ("AZURE_RETRY__MAX_RETRIES".to_string(), "2".to_string()),
+        ]);
+        assert!(!format!("{config:?}").contains("secret"));
+
+        let options: TestOptions = config.options(Some("test")).unwrap();
+        assert_eq!(options.name, "file");
+        let options: crate::ClientOptions = config.options(Some("test")).unwrap();
+        assert_eq!(
+            options.cloud(),
+            &crate::cloud::CloudConfiguration::AzurePublic
+        );
+        assert!(options.retry().delay(2).is_some());
+        assert!(options.retry().delay(3).is_none());
+    }
+
+    #[test]
+    fn flattened_client_options() {
+        #[derive(Debug, Default, Deserialize)]
+        #[serde(default)]
+        struct ServiceOptions {
+            #[serde(flatten)]
+            client_options: crate::ClientOptions,
+            api_version: String,
+        }
+
+        let mut config =
+            Config::from_json(r#"{"test":{"api_version":"7.4","retry":{"max_retries":1}}}"#)
+                .unwrap();
+        config.with_vars([
+            ("AZURE_RETRY__MAX_RETRIES".to_string(), "5".to_string()),
+            ("AZURE_TEST__API_VERSION".to_string(), "2024".to_string()),
+        ]);
+
+        let options: ServiceOptions = config.options(Some("test")).unwrap();
+        assert_eq!(options.api_version, "2024");
+        assert!(options.client_options.retry().delay(5).is_some());
+        assert!(options.client_options.retry().delay(6).is_none());
+    }
+
+    #[test]
+    fn parses_durations() {
+        let parse = |value: &str| {
+            Config::from_json(&format!(r#"{{"timeout":{value}}}"#))
+                .unwrap()
+                .options::<TestOptions>(None)
+                .map(|options| options.timeout)
+        };
+        assert_eq!(parse("3").unwrap(), Some(Duration::from_secs(3)));
+        assert_eq!(
+            parse(r#""500ms""#).unwrap(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            parse(r#""1.5s""#).unwrap(),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(parse(r#""2m""#).unwrap(), Some(Duration::from_secs(120)));
+        assert_eq!(parse("null").unwrap(), None);
+        assert!(parse(r#""soon""#).is_err());
+    }
+
+    #[test]
+    fn client_options() {
+        let mut config = Config::from_json(
+            r#"{"cloud":"azure_china","retry":{"mode":"fixed","delay":"1s"},"keyvault":{"retry":{"max_retries":1}}}"#,
+        )
+        .unwrap();
+        config.with_vars([("AZURE_RETRY__DELAY".to_string(), "250ms".to_string())]);
+
+        let options: crate::ClientOptions = config.options(Some("keyvault")).unwrap();
+        assert_eq!(
+            options.cloud(),
+            &crate::cloud::CloudConfiguration::AzureChina
+        );
+        assert_eq!(options.retry().delay(1), Some(Duration::from_millis(250)));
+        assert_eq!(options.retry().delay(2), None);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn http_transport_options() {
+        use crate::{HttpVersion, TlsVersion};
+
+        let options: crate::HttpTransportOptions = Config::from_json(
+            r#"{
+                "proxy": {"https": "proxy:8080", "no_proxy": "localhost, .contoso.com"},
+                "tls": {"min_version": "tls1_3"},
+                "connection": {"connect_timeout": 10, "idle_timeout": null, "http_version": "http1_only"}
+            }"#,
+        )
+        .unwrap()
+        .options(None)
+        .unwrap();
+
+        assert_eq!(
+            options.proxy.https.unwrap().url().as_str(),
+            "http://proxy:8080/"
+        );
+        assert_eq!(options.proxy.no_proxy, ["localhost", ".contoso.com"]);
+        assert_eq!(options.tls.min_version, TlsVersion::Tls1_3);
+        assert_eq!(
+            options.connection.connect_timeout,
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(options.connection.idle_timeout, None);
+        assert_eq!(options.connection.http_version, HttpVersion::Http1Only);
+        assert_eq!(options.connection.max_idle_per_host, usize::MAX);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() {
+        let config = Config::from_toml(
+            r#"
+            count = 1
+
+            [test]
+            name = "toml"
+            "#,
+        )
+        .unwrap();
+        let options: TestOptions = config.options(Some("test")).unwrap();
+        assert_eq!(options.name, "toml");
+        assert_eq!(options.count, 1);
+    }
+}
@@ -0,0 +1,299 @@
+//! Parse and format dates used by Azure services.
+//!
+//! Each supported format has a module that can be used with `#[serde(with = "...")]`,
+//! along with an `option` module for `Option<OffsetDateTime>` fields:
+//!
+//! ```
+//! use azure_core::date::OffsetDateTime;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Attributes {
+//!     #[serde(with = "azure_core::date::unix_time")]
+//!     created: OffsetDateTime,
+//!     #[serde(default, with = "azure_core::date::unix_time::option")]
+//!     exp: Option<OffsetDateTime>,
+//! }
+//! ```
+
+use crate::error::{ErrorKind, ResultExt};
+use time::{
+    format_description::{well_known::Rfc3339, FormatItem},
+    macros::format_description,
+    PrimitiveDateTime, UtcOffset,
+};
+
+pub use time::OffsetDateTime;
+
+const RFC1123_FORMAT: &[FormatItem] = format_description!(
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+);
+
+const RFC3339_FORMAT: &[FormatItem] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]Z");
+
+const RFC3339_SUBSECOND_FORMAT: &[FormatItem] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]Z");
+
+/// Parse an RFC 1123 date e.g., `Sun, 06 Nov 1994 08:49:37 GMT` as used in HTTP headers.
+pub fn parse_rfc1123(s: &str) -> crate::Result<OffsetDateTime> {
+    Ok(PrimitiveDateTime::parse(s, RFC1123_FORMAT)
+        .with_context(ErrorKind::DataConversion, || {
+            format!("unable to parse RFC 1123 date '{s}'")
+        })?
+        .assume_utc())
+}
+
+/// Format a date as RFC 1123 e.g., `Sun, 06 Nov 1994 08:49:37 GMT` as used in HTTP headers.
+pub fn to_rfc1123(date: &OffsetDateTime) -> String {
+    date.to_offset(UtcOffset::UTC)
+        .format(RFC1123_FORMAT)
+        .expect("RFC 1123 format contains only date and time components")
+}
+
+/// Parse an RFC 3339 date e.g., `1994-11-06T08:49:37.123Z` as used in JSON models.
+pub fn parse_rfc3339(s: &str) -> crate::Result<OffsetDateTime> {
+    OffsetDateTime::parse(s, &Rfc3339).with_context(ErrorKind::DataConversion, || {
+        format!("unable to parse RFC 3339 date '{s}'")
+    })
+}
+
+/// Format a date as RFC 3339 in UTC e.g., `1994-11-06T08:49:37.123Z` as used in JSON models.
+///
+/// Fractional seconds are included only if not zero.
+pub fn to_rfc3339(date: &OffsetDateTime) -> String {
+    let format = match date.nanosecond() {
+        0 => RFC3339_FORMAT,
+        _ => RFC3339_SUBSECOND_FORMAT,
+    };
+    date.to_offset(UtcOffset::UTC)
+        .format(format)
+        .expect("RFC 3339 format contains only date and time components")
+}
+
+/// Convert seconds since the Unix epoch to a date.
+pub fn from_unix_time(seconds: i64) -> crate::Result<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp(seconds).with_context(ErrorKind::DataConversion, || {
+        format!("unable to convert Unix time {seconds}")
+    })
+}
+
+/// Convert a date to seconds since the Unix epoch.
+pub fn to_unix_time(date: &OffsetDateTime) -> i64 {
+    date.unix_timestamp()
+}
+
+/// Serialize and deserialize RFC 1123 dates.
+pub mod rfc1123 {
+    use super::*;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse_rfc1123(&s).map_err(de::Error::custom)
+    }
+
+    pub fn serialize<S>(date: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&to_rfc1123(date))
+    }
+
+    /// Serialize and deserialize optional RFC 1123 dates.
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|s| parse_rfc1123(&s).map_err(de::Error::custom))
+                .transpose()
+        }
+
+        pub fn serialize<S>(date: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match date {
+                Some(date) => serializer.serialize_some(&to_rfc1123(date)),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Serialize and deserialize RFC 3339 dates.
+pub mod rfc3339 {
+    use super::*;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse_rfc3339(&s).map_err(de::Error::custom)
+    }
+
+    pub fn serialize<S>(date: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&to_rfc3339(date))
+    }
+
+    /// Serialize and deserialize optional RFC 3339 dates.
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|s| parse_rfc3339(&s).map_err(de::Error::custom))
+                .transpose()
+        }
+
+        pub fn serialize<S>(date: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match date {
+                Some(date) => serializer.serialize_some(&to_rfc3339(date)),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Serialize and deserialize dates as seconds since the Unix epoch.
+pub mod unix_time {
+    use super::*;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(deserializer)?;
+        from_unix_time(seconds).map_err(de::Error::custom)
+    }
+
+    pub fn serialize<S>(date: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(to_unix_time(date))
+    }
+
+    /// Serialize and deserialize optional dates as seconds since the Unix epoch.
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<i64>::deserialize(deserializer)?
+                .map(|seconds| from_unix_time(seconds).map_err(de::Error::custom))
+                .transpose()
+        }
+
+        pub fn serialize<S>(date: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match date {
+                Some(date) => serializer.serialize_some(&to_unix_time(date)),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use time::macros::datetime;
+
+    #[test]
+    fn rfc1123_roundtrip() {
+        let date = parse_rfc1123("Sun, 06 Nov 1994 08:49:37 GMT").expect("valid date");
+        assert_eq!(date, datetime!(1994-11-06 08:49:37 UTC));
+        assert_eq!(to_rfc1123(&date), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn rfc1123_converts_to_utc() {
+        let date = datetime!(1994-11-06 00:49:37 -8);
+        assert_eq!(to_rfc1123(&date), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn rfc1123_invalid() {
+        let error = parse_rfc1123("1994-11-06T08:49:37Z").expect_err("invalid date");
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[test]
+    fn rfc3339_roundtrip() {
+        let date = parse_rfc3339("1994-11-06T00:49:37.1234567-08:00").expect("valid date");
+        assert_eq!(date, datetime!(1994-11-06 08:49:37.1234567 UTC));
+        assert_eq!(to_rfc3339(&date), "1994-11-06T08:49:37.1234567Z");
+        assert_eq!(
+            to_rfc3339(&datetime!(1994-11-06 08:49:37 UTC)),
+            "1994-11-06T08:49:37Z"
+        );
+    }
+
+    #[test]
+    fn rfc3339_invalid() {
+        let error = parse_rfc3339("Sun, 06 Nov 1994 08:49:37 GMT").expect_err("invalid date");
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Model {
+        #[serde(with = "unix_time")]
+        created: OffsetDateTime,
+        #[serde(default, with = "rfc3339::option")]
+        updated: Option<OffsetDateTime>,
+        #[serde(default, with = "unix_time::option")]
+        exp: Option<OffsetDateTime>,
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let json = r#"{"created":784111777,"updated":"1994-11-06T08:49:37Z","exp":null}"#;
+        let model: Model = serde_json::from_str(json).expect("valid model");
+        assert_eq!(
+            model,
+            Model {
+                created: datetime!(1994-11-06 08:49:37 UTC),
+                updated: Some(datetime!(1994-11-06 08:49:37 UTC)),
+                exp: None,
+            }
+        );
+        assert_eq!(serde_json::to_string(&model).expect("serialize"), json);
+
+        let model: Model = serde_json::from_str(r#"{"created":784111777}"#).expect("valid model");
+        assert_eq!(model.updated, None);
+    }
+
+    #[test]
+    fn serde_invalid() {
+        let error: crate::Error =
+            serde_json::from_str::<Model>(r#"{"created":784111777,"updated":"invalid"}"#)
+                .expect_err("invalid date")
+                .into();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+}
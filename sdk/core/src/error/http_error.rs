@@ -0,0 +1,254 @@
+use crate::{
+    error::{Error, ErrorKind},
+    headers::{Headers, ERROR_CODE, REQUEST_ID},
+    Response,
+};
+use bytes::Bytes;
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// The maximum number of bytes of an error response body that are captured.
+pub const MAX_ERROR_BODY_LEN: usize = 64 * 1024;
+
+/// An HTTP error response returned from a service.
+///
+/// Contains a snapshot of the failing response including all headers and up to
+/// [`MAX_ERROR_BODY_LEN`] bytes of the body.
+#[derive(Clone, Debug)]
+pub struct HttpError {
+    status: u16,
+    headers: Headers,
+    body: Bytes,
+    error_code: Option<String>,
+    message: Option<String>,
+    inner_error_codes: Vec<String>,
+}
+
+impl HttpError {
+    /// Create an [`HttpError`] from a [`Response`], reading up to [`MAX_ERROR_BODY_LEN`] bytes of the response body.
+    ///
+    /// The error code is taken from the `x-ms-error-code` header if present;
+    /// otherwise, from the `code` of a standard Azure error response body.
+    pub async fn new(response: Response) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response
+            .into_body()
+            .collect_limited(MAX_ERROR_BODY_LEN)
+            .await
+            .unwrap_or_default();
+
+        let details = serde_json::from_slice::<ErrorResponse>(&body)
+            .ok()
+            .and_then(|r| r.error);
+
+        let mut inner_error_codes = Vec::new();
+        let mut inner_error = details.as_ref().and_then(|d| d.inner_error.as_deref());
+        while let Some(inner) = inner_error {
+            if let Some(code) = &inner.code {
+                inner_error_codes.push(code.clone());
+            }
+            inner_error = inner.inner_error.as_deref();
+        }
+
+        let (code, message) = details.map_or((None, None), |d| (d.code, d.message));
+
+        Self {
+            status,
+            error_code: headers.get_optional_string(&ERROR_CODE).or(code),
+            headers,
+            body,
+            message,
+            inner_error_codes,
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// The response body, truncated to [`MAX_ERROR_BODY_LEN`] bytes.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    pub fn error_code(&self) -> Option<&str> {
+        self.error_code.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Error codes from any nested `innererror` objects, from outermost to innermost.
+    pub fn inner_error_codes(&self) -> &[String] {
+        &self.inner_error_codes
+    }
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HTTP {} ({})",
+            self.status,
+            self.error_code.as_deref().unwrap_or("unknown")
+        )?;
+        if let Some(message) = &self.message {
+            write!(f, "\nMessage: {message}")?;
+        }
+        if !self.inner_error_codes.is_empty() {
+            write!(f, "\nInner errors: {}", self.inner_error_codes.join(" -> "))?;
+        }
+        if let Some(request_id) = self.headers.get_optional_str(&REQUEST_ID) {
+            write!(f, "\nRequest ID: {request_id}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// Converts `401 Unauthorized` to [`ErrorKind::Authentication`], `403 Forbidden` to [`ErrorKind::Authorization`],
+/// and any other status to [`ErrorKind::HttpResponse`]. The [`HttpError`] is available from [`Error::http_response`].
+impl From<HttpError> for Error {
+    fn from(error: HttpError) -> Self {
+        let kind = match error.status {
+            401 => ErrorKind::Authentication,
+            403 => ErrorKind::Authorization,
+            status => ErrorKind::HttpResponse {
+                status,
+                error_code: error.error_code.clone(),
+            },
+        };
+        Error::new(kind, error)
+    }
+}
+
+/// Whether the status code is a successful (2xx) status.
+pub(crate) fn is_success(status: u16) -> bool {
+    (200..300).contains(&status)
+}
+
+/// Whether the status code indicates a transient failure that may succeed if retried.
+pub(crate) fn is_transient_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: Option<ErrorDetails>,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetails {
+    code: Option<String>,
+    message: Option<String>,
+    #[serde(rename = "innererror")]
+    inner_error: Option<Box<InnerError>>,
+}
+
+#[derive(Deserialize)]
+struct InnerError {
+    code: Option<String>,
+    #[serde(rename = "innererror")]
+    inner_error: Option<Box<InnerError>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stream::BytesStream, Headers};
+
+    fn response(status: u16, headers: Headers, body: &'static str) -> Response {
+        Response::new(status, headers, Box::pin(BytesStream::new(body)))
+    }
+
+    #[tokio::test]
+    async fn error_code_from_body() {
+        let body = r#"{"error":{"code":"Forbidden","message":"Access denied","innererror":{"code":"AccessDenied","innererror":{"code":"ForbiddenByPolicy"}}}}"#;
+        let error = HttpError::new(response(403, Headers::new(), body)).await;
+
+        assert_eq!(error.status(), 403);
+        assert_eq!(error.error_code(), Some("Forbidden"));
+        assert_eq!(error.message(), Some("Access denied"));
+        assert_eq!(
+            error.inner_error_codes(),
+            &["AccessDenied".to_string(), "ForbiddenByPolicy".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn authentication_and_authorization() {
+        let error: Error = HttpError::new(response(401, Headers::new(), ""))
+            .await
+            .into();
+        assert_eq!(error.kind(), &ErrorKind::Authentication);
+        assert_eq!(error.http_response().map(HttpError::status), Some(401));
+
+        let error: Error = HttpError::new(response(403, Headers::new(), ""))
+            .await
+            .into();
+        assert_eq!(error.kind(), &ErrorKind::Authorization);
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn error_code_from_header() {
+        let mut headers = Headers::new();
+        headers.insert(ERROR_CODE, "SecretNotFound");
+        let body = r#"{"error":{"code":"NotFound","message":"Secret not found"}}"#;
+        let error: Error = HttpError::new(response(404, headers, body)).await.into();
+
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::HttpResponse {
+                status: 404,
+                error_code: Some("SecretNotFound".to_string())
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn error_snapshot() {
+        let mut headers = Headers::new();
+        headers.insert(REQUEST_ID, "8a3e3f3c");
+        let body = r#"{"error":{"code":"Throttled","message":"Too many requests","innererror":{"code":"RateLimited"}}}"#;
+        let error: Error = HttpError::new(response(429, headers, body)).await.into();
+
+        let http_error = error.http_response().expect("expected HttpError");
+        assert_eq!(http_error.status(), 429);
+        assert_eq!(
+            http_error.headers().get_optional_str(&REQUEST_ID),
+            Some("8a3e3f3c")
+        );
+        assert_eq!(http_error.body(), body.as_bytes());
+        assert_eq!(
+            error.to_string(),
+            "HTTP 429 (Throttled)\nMessage: Too many requests\nInner errors: RateLimited\nRequest ID: 8a3e3f3c"
+        );
+    }
+
+    #[tokio::test]
+    async fn error_body_truncated() {
+        let body = "x".repeat(MAX_ERROR_BODY_LEN + 1);
+        let response = Response::new(500, Headers::new(), Box::pin(BytesStream::new(body)));
+        let error = HttpError::new(response).await;
+
+        assert_eq!(error.body().len(), MAX_ERROR_BODY_LEN);
+    }
+
+    #[tokio::test]
+    async fn error_without_body() {
+        let error = HttpError::new(response(500, Headers::new(), "")).await;
+
+        assert_eq!(error.status(), 500);
+        assert_eq!(error.error_code(), None);
+        assert_eq!(error.message(), None);
+        assert!(error.inner_error_codes().is_empty());
+    }
+}
@@ -1,3 +1,7 @@
+mod http_error;
+
+pub use http_error::*;
+
 use std::{
     borrow::Cow,
     fmt::{Debug, Display},
@@ -7,6 +11,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ErrorKind {
+    /// The service returned an unsuccessful status code other than 401 or 403.
     HttpResponse {
         status: u16,
         error_code: Option<String>,
@@ -14,6 +19,20 @@ pub enum ErrorKind {
     Io,
     DataConversion,
     Credential,
+    /// The client failed to authenticate e.g., a token could not be acquired or the service returned `401 Unauthorized`.
+    Authentication,
+    /// The client authenticated but is not permitted to perform the operation e.g., the service returned `403 Forbidden`.
+    Authorization,
+    /// An operation did not complete in the allotted time.
+    Timeout,
+    /// An operation was cancelled by the caller.
+    Cancelled,
+    /// A connection could not be established or was interrupted.
+    Connection,
+    /// The client throttled the request before it was sent. It is not retried, so a wait limit is not exceeded.
+    Throttled,
+    /// The circuit breaker for the endpoint is open so the request was not sent.
+    CircuitOpen,
     Other,
 }
 
@@ -39,6 +58,13 @@ impl Display for ErrorKind {
             ErrorKind::Credential => write!(f, "Credential"),
             ErrorKind::DataConversion => write!(f, "DataConversion"),
             ErrorKind::Io => write!(f, "Io"),
+            ErrorKind::Authentication => write!(f, "Authentication"),
+            ErrorKind::Authorization => write!(f, "Authorization"),
+            ErrorKind::Timeout => write!(f, "Timeout"),
+            ErrorKind::Cancelled => write!(f, "Cancelled"),
+            ErrorKind::Connection => write!(f, "Connection"),
+            ErrorKind::Throttled => write!(f, "Throttled"),
+            ErrorKind::CircuitOpen => write!(f, "CircuitOpen"),
             ErrorKind::Other => write!(f, "Other"),
         }
     }
@@ -71,6 +97,35 @@ impl Error {
         }
     }
 
+    /// Whether the error is caused by a temporary condition that is expected to resolve itself.
+    ///
+    /// Timeouts, connection failures, and HTTP status codes 408, 429, 500, 502, 503, and 504 are transient.
+    pub fn is_transient(&self) -> bool {
+        match self.kind() {
+            ErrorKind::HttpResponse { status, .. } => is_transient_status(*status),
+            ErrorKind::Timeout | ErrorKind::Connection => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the request that caused the error may be sent again.
+    ///
+    /// Only transient errors are retryable. Resending the request would not fix other errors, such as failing to read
+    /// a local file or deserialize a response, or the client throttling the request before it was sent.
+    pub fn is_retryable(&self) -> bool {
+        self.is_transient()
+    }
+
+    /// Get the [`HttpError`] captured from a failed response, if any.
+    pub fn http_response(&self) -> Option<&HttpError> {
+        match &self.context {
+            Context::Custom(Custom { error, .. }) | Context::Full(Custom { error, .. }, _) => {
+                error.downcast_ref()
+            }
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn message<C>(kind: ErrorKind, message: C) -> Self
     where
@@ -132,13 +187,30 @@ impl From<ErrorKind> for Error {
 
 impl From<std::io::Error> for Error {
     fn from(error: std::io::Error) -> Self {
-        Self::new(ErrorKind::Io, error)
+        use std::io::ErrorKind as E;
+        let kind = match error.kind() {
+            E::TimedOut => ErrorKind::Timeout,
+            E::ConnectionRefused
+            | E::ConnectionReset
+            | E::ConnectionAborted
+            | E::NotConnected
+            | E::BrokenPipe
+            | E::AddrInUse
+            | E::AddrNotAvailable => ErrorKind::Connection,
+            E::InvalidData => ErrorKind::DataConversion,
+            _ => ErrorKind::Io,
+        };
+        Self::new(kind, error)
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Self {
-        Self::new(ErrorKind::DataConversion, error)
+        let kind = match error.classify() {
+            serde_json::error::Category::Io => ErrorKind::Io,
+            _ => ErrorKind::DataConversion,
+        };
+        Self::new(kind, error)
     }
 }
 
@@ -221,3 +293,54 @@ where
         self.context(kind, f())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_error_kinds() {
+        use std::io::{Error as IoError, ErrorKind as E};
+
+        let error: Error = IoError::from(E::TimedOut).into();
+        assert_eq!(error.kind(), &ErrorKind::Timeout);
+        assert!(error.is_transient());
+
+        let error: Error = IoError::from(E::ConnectionReset).into();
+        assert_eq!(error.kind(), &ErrorKind::Connection);
+        assert!(error.is_retryable());
+
+        let error: Error = IoError::from(E::UnexpectedEof).into();
+        assert_eq!(error.kind(), &ErrorKind::Io);
+        assert!(!error.is_transient());
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn http_response_classification() {
+        let error: Error = ErrorKind::HttpResponse {
+            status: 503,
+            error_code: None,
+        }
+        .into();
+        assert!(error.is_transient());
+        assert!(error.is_retryable());
+
+        let error: Error = ErrorKind::HttpResponse {
+            status: 404,
+            error_code: None,
+        }
+        .into();
+        assert!(!error.is_transient());
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn cancelled_and_throttled_not_retryable() {
+        for kind in [ErrorKind::Cancelled, ErrorKind::Throttled] {
+            let error: Error = kind.into();
+            assert!(!error.is_transient());
+            assert!(!error.is_retryable());
+        }
+    }
+}
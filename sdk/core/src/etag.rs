@@ -0,0 +1,74 @@
+use crate::headers::{self, Header, HeaderName, HeaderValue};
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+/// An entity tag identifying a specific version of a resource.
+///
+/// The value is kept exactly as returned by the service, including any quotes or weak validator prefix.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Etag(String);
+
+impl Etag {
+    /// An [`Etag`] matching any version of a resource.
+    pub fn any() -> Self {
+        Self("*".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Display for Etag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Etag {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Etag {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl FromStr for Etag {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+/// Sets the `etag` header, e.g. of a mock response; use [`MatchConditions`](crate::MatchConditions) for conditional requests.
+impl Header for Etag {
+    fn name(&self) -> HeaderName {
+        headers::ETAG
+    }
+
+    fn value(&self) -> HeaderValue {
+        self.0.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Headers;
+
+    #[test]
+    fn etag_header() {
+        let mut headers = Headers::new();
+        headers.add(Etag::from("W/\"0x8D\""));
+
+        assert_eq!(headers.get_optional_str(&headers::ETAG), Some("W/\"0x8D\""));
+        assert_eq!(
+            headers.get_as::<Etag, _>(&headers::ETAG).unwrap(),
+            Etag::from("W/\"0x8D\"")
+        );
+    }
+}
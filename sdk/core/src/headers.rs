@@ -37,8 +37,10 @@ pub trait Header {
 }
 
 /// A collection of headers
+///
+/// Headers are kept in insertion order and a header name may have multiple values.
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
-pub struct Headers(std::collections::HashMap<HeaderName, HeaderValue>);
+pub struct Headers(Vec<(HeaderName, HeaderValue)>);
 
 impl Headers {
     pub fn new() -> Self {
@@ -85,6 +87,9 @@ impl Headers {
         })
     }
 
+    /// Parse the first value of a header, if present.
+    ///
+    /// Use [`Headers::get_joined`] to parse all values of a header as a single comma-separated value.
     pub fn get_optional_with<'a, V, F, E>(
         &'a self,
         key: &HeaderName,
@@ -94,8 +99,7 @@ impl Headers {
         F: FnOnce(&'a HeaderValue) -> Result<V, E>,
         E: std::error::Error + Send + Sync + 'static,
     {
-        self.0
-            .get(key)
+        self.get(key)
             .map(|v: &HeaderValue| {
                 parser(v).with_context(ErrorKind::DataConversion, || {
                     let ty = std::any::type_name::<V>();
@@ -105,44 +109,119 @@ impl Headers {
             .transpose()
     }
 
-    /// Insert a header name/value pair
+    /// Get the first value of a header, if present.
+    pub fn get(&self, key: &HeaderName) -> Option<&HeaderValue> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Iterate over all values of a header in the order they were added.
+    pub fn get_all<'a>(&'a self, key: &HeaderName) -> impl Iterator<Item = &'a HeaderValue> + 'a {
+        let key = key.clone();
+        self.0
+            .iter()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Get all values of a header joined by commas, if present.
+    ///
+    /// Do not use this for headers like `set-cookie` whose values may themselves contain commas.
+    pub fn get_joined(&self, key: &HeaderName) -> Option<HeaderValue> {
+        let mut values = self.get_all(key);
+        let first = values.next()?;
+        let mut joined = first.as_str().to_owned();
+        for value in values {
+            joined.push_str(", ");
+            joined.push_str(value.as_str());
+        }
+        Some(joined.into())
+    }
+
+    /// Whether the header is present.
+    pub fn contains(&self, key: &HeaderName) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Insert a header name/value pair, replacing any existing values for the header name.
+    ///
+    /// The header keeps the position of the first existing value, if any.
     pub fn insert<K, V>(&mut self, key: K, value: V)
     where
         K: Into<HeaderName>,
         V: Into<HeaderValue>,
     {
-        self.0.insert(key.into(), value.into());
+        let key = key.into();
+        let value = value.into();
+        match self.0.iter().position(|(k, _)| *k == key) {
+            Some(i) => {
+                self.0[i].1 = value;
+                let rest = self.0.split_off(i + 1);
+                self.0.extend(rest.into_iter().filter(|(k, _)| *k != key));
+            }
+            None => self.0.push((key, value)),
+        }
     }
 
-    /// Add headers to the headers collection
+    /// Append a header name/value pair, keeping any existing values for the header name.
+    pub fn append<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<HeaderName>,
+        V: Into<HeaderValue>,
+    {
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Remove all values for a header name, returning the first value if any.
+    pub fn remove(&mut self, key: &HeaderName) -> Option<HeaderValue> {
+        let value = self.get(key).cloned();
+        self.0.retain(|(k, _)| k != key);
+        value
+    }
+
+    /// Add headers to the headers collection, keeping any existing values and every value for the same header name.
     pub fn add<H>(&mut self, header: H)
     where
         H: AsHeaders,
     {
         for (key, value) in header.as_headers() {
-            self.insert(key, value);
+            self.append(key, value);
         }
     }
 
-    /// Iterate over all the header name/value pairs
+    /// Iterate over all the header name/value pairs in the order they were added
     pub fn iter(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
-        self.0.iter()
+        self.0.iter().map(|(k, v)| (k, v))
+    }
+
+    /// The number of header name/value pairs.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 }
 
 impl IntoIterator for Headers {
     type Item = (HeaderName, HeaderValue);
 
-    type IntoIter = std::collections::hash_map::IntoIter<HeaderName, HeaderValue>;
+    type IntoIter = std::vec::IntoIter<(HeaderName, HeaderValue)>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
 
+impl FromIterator<(HeaderName, HeaderValue)> for Headers {
+    fn from_iter<I: IntoIterator<Item = (HeaderName, HeaderValue)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl From<std::collections::HashMap<HeaderName, HeaderValue>> for Headers {
     fn from(c: std::collections::HashMap<HeaderName, HeaderValue>) -> Self {
-        Self(c)
+        c.into_iter().collect()
     }
 }
 
@@ -237,15 +316,105 @@ impl From<&String> for HeaderValue {
 }
 
 pub const ACCEPT: HeaderName = HeaderName::from_static("accept");
+pub const AZURE_ASYNCOPERATION: HeaderName = HeaderName::from_static("azure-asyncoperation");
 pub const AUTHORIZATION: HeaderName = HeaderName::from_static("authorization");
 pub const CLIENT_REQUEST_ID: HeaderName = HeaderName::from_static("x-ms-client-request-id");
 pub const CONTENT_ENCODING: HeaderName = HeaderName::from_static("content-encoding");
 pub const CONTENT_LENGTH: HeaderName = HeaderName::from_static("content-length");
+pub const CONTENT_TYPE: HeaderName = HeaderName::from_static("content-type");
+pub const ERROR_CODE: HeaderName = HeaderName::from_static("x-ms-error-code");
 pub const ETAG: HeaderName = HeaderName::from_static("etag");
 pub const IF_MATCH: HeaderName = HeaderName::from_static("if-match");
 pub const IF_MODIFIED_SINCE: HeaderName = HeaderName::from_static("if-modified-since");
 pub const IF_NONE_MATCH: HeaderName = HeaderName::from_static("if-none-match");
 pub const IF_UNMODIFIED_SINCE: HeaderName = HeaderName::from_static("if-unmodified-since");
+pub const LAST_MODIFIED: HeaderName = HeaderName::from_static("last-modified");
+pub const LOCATION: HeaderName = HeaderName::from_static("location");
+pub const OPERATION_LOCATION: HeaderName = HeaderName::from_static("operation-location");
+pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-ms-request-id");
+pub const RETRY_AFTER: HeaderName = HeaderName::from_static("retry-after");
+pub const RETRY_AFTER_MS: HeaderName = HeaderName::from_static("retry-after-ms");
 pub const TAGS: HeaderName = HeaderName::from_static("x-ms-tags");
 pub const USER_AGENT: HeaderName = HeaderName::from_static("user-agent");
 pub const WWW_AUTHENTICATE: HeaderName = HeaderName::from_static("www-authenticate");
+pub const X_MS_RETRY_AFTER_MS: HeaderName = HeaderName::from_static("x-ms-retry-after-ms");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preserves_order() {
+        let mut headers = Headers::new();
+        headers.insert(USER_AGENT, "test");
+        headers.insert(ACCEPT, "application/json");
+        headers.insert(CLIENT_REQUEST_ID, "1234");
+
+        let names: Vec<&str> = headers.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(names, ["user-agent", "accept", "x-ms-client-request-id"]);
+    }
+
+    #[test]
+    fn append_multiple_values() {
+        let mut headers = Headers::new();
+        headers.append(WWW_AUTHENTICATE, "Bearer realm=\"a\"");
+        headers.insert(ACCEPT, "application/json");
+        headers.append(WWW_AUTHENTICATE, "Basic");
+
+        let values: Vec<&str> = headers
+            .get_all(&WWW_AUTHENTICATE)
+            .map(HeaderValue::as_str)
+            .collect();
+        assert_eq!(values, ["Bearer realm=\"a\"", "Basic"]);
+        assert_eq!(
+            headers.get_optional_str(&WWW_AUTHENTICATE),
+            Some("Bearer realm=\"a\"")
+        );
+        assert_eq!(
+            headers.get_joined(&WWW_AUTHENTICATE),
+            Some(HeaderValue::from("Bearer realm=\"a\", Basic"))
+        );
+    }
+
+    #[test]
+    fn add_keeps_multiple_values() {
+        struct Challenges;
+
+        impl AsHeaders for Challenges {
+            type Iter = std::vec::IntoIter<(HeaderName, HeaderValue)>;
+
+            fn as_headers(&self) -> Self::Iter {
+                vec![
+                    (WWW_AUTHENTICATE, "Bearer".into()),
+                    (WWW_AUTHENTICATE, "Basic".into()),
+                ]
+                .into_iter()
+            }
+        }
+
+        let mut headers = Headers::new();
+        headers.add(Challenges);
+        let values: Vec<&str> = headers
+            .get_all(&WWW_AUTHENTICATE)
+            .map(HeaderValue::as_str)
+            .collect();
+        assert_eq!(values, ["Bearer", "Basic"]);
+    }
+
+    #[test]
+    fn insert_replaces_values() {
+        let mut headers = Headers::new();
+        headers.append(IF_MATCH, "\"a\"");
+        headers.append(ACCEPT, "application/json");
+        headers.append(IF_MATCH, "\"b\"");
+        headers.insert(IF_MATCH, "\"c\"");
+
+        assert_eq!(headers.len(), 2);
+        let names: Vec<&str> = headers.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(names, ["if-match", "accept"]);
+        assert_eq!(headers.get_optional_str(&IF_MATCH), Some("\"c\""));
+
+        assert_eq!(headers.remove(&IF_MATCH), Some(HeaderValue::from("\"c\"")));
+        assert!(!headers.contains(&IF_MATCH));
+    }
+}
@@ -1,24 +1,48 @@
 mod auth;
+pub mod base64;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cancellation;
+pub mod cloud;
+mod conditions;
+pub mod config;
 mod context;
+pub mod date;
 mod error;
+mod etag;
 mod headers;
 pub mod json;
 mod options;
+mod paging;
+mod patch;
 mod pipeline;
 pub mod policies;
+mod polling;
 mod request;
 mod response;
+mod runtime;
 pub mod stream;
+#[cfg(test)]
+mod testing;
 mod trace;
+#[cfg(feature = "xml")]
+pub mod xml;
 
 pub use auth::*;
+pub use cancellation::*;
+pub use conditions::*;
 pub use context::*;
 pub use error::*;
+pub use etag::*;
 pub use headers::*;
 pub use options::*;
+pub use paging::*;
+pub use patch::*;
 pub use pipeline::*;
+pub use polling::*;
 pub use request::*;
 pub use response::*;
+pub use runtime::*;
 pub use trace::*;
 
 // Re-export common types.
@@ -5,19 +5,86 @@ pub use builders::*;
 pub use retry::*;
 pub use transport::*;
 
-use crate::context::Context;
+use crate::{
+    cloud::CloudConfiguration,
+    context::Context,
+    headers::{HeaderName, HeaderValue},
+    policies::{CustomHeaders, CustomQueryParameters, FaultInjectionOptions, Policy},
+    runtime::{default_runtime, AsyncRuntime},
+};
+use serde::Deserialize;
+use std::sync::Arc;
 
-#[derive(Clone, Debug, Default)]
+/// Options common to all clients.
+///
+/// Only `cloud`, `retry`, and `transport` are deserialized, e.g. by [`Config`](crate::config::Config);
+/// `transport` deserializes [`HttpTransportOptions`] and requires the `reqwest` feature.
+///
+/// A `transport` with settings other than the defaults creates a new HTTP client and connection pool each time
+/// options are deserialized; default settings share one. Load options once and clone them for each client.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
 pub struct ClientOptions {
+    cloud: CloudConfiguration,
+    #[serde(skip)]
+    fault_injection: Option<FaultInjectionOptions>,
+    #[serde(skip)]
+    per_call_policies: Vec<Arc<dyn Policy>>,
+    #[serde(skip)]
+    per_retry_policies: Vec<Arc<dyn Policy>>,
     retry: RetryOptions,
+    #[serde(skip)]
+    runtime: Arc<dyn AsyncRuntime>,
+    #[cfg_attr(not(feature = "reqwest"), serde(skip))]
     transport: TransportOptions,
 }
 
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            cloud: CloudConfiguration::default(),
+            fault_injection: None,
+            per_call_policies: Vec::new(),
+            per_retry_policies: Vec::new(),
+            retry: RetryOptions::default(),
+            runtime: default_runtime(),
+            transport: TransportOptions::default(),
+        }
+    }
+}
+
 impl ClientOptions {
+    /// The cloud that credentials and clients derive authorities and scopes from. The default is [`CloudConfiguration::AzurePublic`].
+    pub fn cloud(&self) -> &CloudConfiguration {
+        &self.cloud
+    }
+
+    /// Faults injected into every request to test how an application handles failures. The default injects none.
+    pub fn fault_injection(&self) -> Option<&FaultInjectionOptions> {
+        self.fault_injection.as_ref()
+    }
+
+    /// Policies called once per client method call. See [`Pipeline`](crate::Pipeline) for the order of policies.
+    pub fn per_call_policies(&self) -> &[Arc<dyn Policy>] {
+        &self.per_call_policies
+    }
+
+    /// Policies called for every attempt including retries. See [`Pipeline`](crate::Pipeline) for the order of policies.
+    pub fn per_retry_policies(&self) -> &[Arc<dyn Policy>] {
+        &self.per_retry_policies
+    }
+
     pub fn retry(&self) -> &RetryOptions {
         &self.retry
     }
 
+    /// The runtime used to wait between retries and polls.
+    ///
+    /// The default is `TokioRuntime` if the `tokio` feature, which `reqwest` enables, is enabled; otherwise, [`StdRuntime`](crate::StdRuntime).
+    pub fn runtime(&self) -> &Arc<dyn AsyncRuntime> {
+        &self.runtime
+    }
+
     pub fn transport(&self) -> &TransportOptions {
         &self.transport
     }
@@ -42,11 +109,46 @@ mod builders {
 
         fn options_mut(&mut self) -> &mut ClientOptions;
 
+        fn with_cloud(&mut self, cloud: impl Into<CloudConfiguration>) -> &mut Self {
+            self.options_mut().cloud = cloud.into();
+            self
+        }
+
+        /// Injects faults into requests. Do not use in production.
+        fn with_fault_injection(
+            &mut self,
+            fault_injection: impl Into<FaultInjectionOptions>,
+        ) -> &mut Self {
+            self.options_mut().fault_injection = Some(fault_injection.into());
+            self
+        }
+
+        fn with_per_call_policies(
+            &mut self,
+            per_call_policies: impl Into<Vec<Arc<dyn Policy>>>,
+        ) -> &mut Self {
+            self.options_mut().per_call_policies = per_call_policies.into();
+            self
+        }
+
+        fn with_per_retry_policies(
+            &mut self,
+            per_retry_policies: impl Into<Vec<Arc<dyn Policy>>>,
+        ) -> &mut Self {
+            self.options_mut().per_retry_policies = per_retry_policies.into();
+            self
+        }
+
         fn with_retry(&mut self, retry: impl Into<RetryOptions>) -> &mut Self {
             self.options_mut().retry = retry.into();
             self
         }
 
+        fn with_runtime(&mut self, runtime: Arc<dyn AsyncRuntime>) -> &mut Self {
+            self.options_mut().runtime = runtime;
+            self
+        }
+
         fn with_transport(&mut self, transport: impl Into<TransportOptions>) -> &mut Self {
             self.options_mut().transport = transport.into();
             self
@@ -58,9 +160,40 @@ mod builders {
 
         fn options_mut(&mut self) -> &mut ClientMethodOptions;
 
+        /// Replaces the [`Context`] including any headers or query parameters already added.
         fn with_context(&mut self, context: Context) -> &mut Self {
             self.options_mut().context = context;
             self
         }
+
+        /// Adds a header to every attempt of the request, replacing any header of the same name.
+        fn with_header(
+            &mut self,
+            name: impl Into<HeaderName>,
+            value: impl Into<HeaderValue>,
+        ) -> &mut Self {
+            let context = &mut self.options_mut().context;
+            let mut headers = context
+                .value::<CustomHeaders>()
+                .cloned()
+                .unwrap_or_default();
+            headers.0.insert(name, value);
+            context.insert(headers);
+            self
+        }
+
+        /// Adds a query parameter to every attempt of the request, replacing any parameter of the same name.
+        fn with_query(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+            let context = &mut self.options_mut().context;
+            let mut parameters = context
+                .value::<CustomQueryParameters>()
+                .cloned()
+                .unwrap_or_default();
+            let name = name.into();
+            parameters.0.retain(|(n, _)| *n != name);
+            parameters.0.push((name, value.into()));
+            context.insert(parameters);
+            self
+        }
     }
 }
@@ -1,4 +1,19 @@
-#[derive(Clone, Debug, Default)]
+use crate::config::{duration, from_str};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// How failed requests are retried.
+///
+/// When deserialized, `mode` is `exponential` (default), `fixed`, or `none`,
+/// and unset `max_retries`, `initial_delay`, `max_delay`, or `delay` use their defaults:
+///
+/// ```
+/// # use azure_core::{config::Config, RetryOptions};
+/// let retry: RetryOptions = Config::from_json(r#"{"mode":"fixed","delay":"1s"}"#)?.options(None)?;
+/// # Ok::<(), azure_core::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(from = "RetrySettings")]
 pub struct RetryOptions {
     mode: RetryMode,
 }
@@ -21,6 +36,32 @@ impl RetryOptions {
             mode: RetryMode::None,
         }
     }
+
+    /// Gets the delay before the given retry attempt, starting at 1, or `None` if no more retries should be attempted.
+    pub(crate) fn delay(&self, attempt: u32) -> Option<Duration> {
+        match &self.mode {
+            RetryMode::Exponential(options) if attempt <= options.max_retries => {
+                let factor = 2u32.saturating_pow(attempt - 1);
+                Some(
+                    options
+                        .initial_delay
+                        .saturating_mul(factor)
+                        .min(options.max_delay),
+                )
+            }
+            RetryMode::Fixed(options) if attempt <= options.max_retries => Some(options.delay),
+            _ => None,
+        }
+    }
+
+    /// Gets the maximum delay before a retry, including any delay requested by the service.
+    pub(crate) fn max_delay(&self) -> Duration {
+        match &self.mode {
+            RetryMode::Exponential(options) => options.max_delay,
+            RetryMode::Fixed(options) => options.max_delay,
+            RetryMode::None => Duration::ZERO,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -37,8 +78,89 @@ impl Default for RetryMode {
     }
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct ExponentialRetryOptions {}
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RetryModeName {
+    #[default]
+    Exponential,
+    Fixed,
+    None,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RetrySettings {
+    mode: RetryModeName,
+    #[serde(with = "from_str::option")]
+    max_retries: Option<u32>,
+    #[serde(with = "duration::option")]
+    initial_delay: Option<Duration>,
+    #[serde(with = "duration::option")]
+    max_delay: Option<Duration>,
+    #[serde(with = "duration::option")]
+    delay: Option<Duration>,
+}
+
+impl From<RetrySettings> for RetryOptions {
+    fn from(settings: RetrySettings) -> Self {
+        match settings.mode {
+            RetryModeName::Exponential => {
+                let default = ExponentialRetryOptions::default();
+                Self::exponential(ExponentialRetryOptions {
+                    initial_delay: settings.initial_delay.unwrap_or(default.initial_delay),
+                    max_retries: settings.max_retries.unwrap_or(default.max_retries),
+                    max_delay: settings.max_delay.unwrap_or(default.max_delay),
+                })
+            }
+            RetryModeName::Fixed => {
+                let default = FixedRetryOptions::default();
+                Self::fixed(FixedRetryOptions {
+                    delay: settings.delay.unwrap_or(default.delay),
+                    max_retries: settings.max_retries.unwrap_or(default.max_retries),
+                    max_delay: settings.max_delay.unwrap_or(default.max_delay),
+                })
+            }
+            RetryModeName::None => Self::none(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExponentialRetryOptions {
+    /// The delay before the first retry, doubled for each subsequent retry.
+    pub initial_delay: Duration,
+    /// The maximum number of retries.
+    pub max_retries: u32,
+    /// The maximum delay between retries, including any delay requested by the service.
+    pub max_delay: Duration,
+}
+
+impl Default for ExponentialRetryOptions {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(800),
+            max_retries: 3,
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
 
-#[derive(Clone, Debug, Default)]
-pub struct FixedRetryOptions {}
+#[derive(Clone, Debug)]
+pub struct FixedRetryOptions {
+    /// The delay between retries unless the service requests a different delay.
+    pub delay: Duration,
+    /// The maximum number of retries.
+    pub max_retries: u32,
+    /// The maximum delay the service may request between retries.
+    pub max_delay: Duration,
+}
+
+impl Default for FixedRetryOptions {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(800),
+            max_retries: 3,
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::{duration, from_str};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Connection pooling and protocol settings for the HTTP transport.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ConnectionOptions {
+    /// The maximum number of idle connections kept open per host. The default is unlimited.
+    #[serde(deserialize_with = "from_str::deserialize")]
+    pub max_idle_per_host: usize,
+
+    /// How long an idle connection is kept open. The default is 90 seconds; `None` keeps connections open indefinitely.
+    #[serde(with = "duration::option")]
+    pub idle_timeout: Option<Duration>,
+
+    /// How long to wait for a connection to be established. The default is no timeout.
+    #[serde(with = "duration::option")]
+    pub connect_timeout: Option<Duration>,
+
+    /// The interval between TCP keep-alive probes. The default disables TCP keep-alive.
+    #[serde(with = "duration::option")]
+    pub tcp_keepalive: Option<Duration>,
+
+    /// The interval between HTTP/2 keep-alive pings. The default disables HTTP/2 keep-alive.
+    #[serde(with = "duration::option")]
+    pub http2_keep_alive_interval: Option<Duration>,
+
+    /// Which HTTP versions to use. The default is [`HttpVersion::Negotiate`].
+    pub http_version: HttpVersion,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: usize::MAX,
+            idle_timeout: Some(Duration::from_secs(90)),
+            connect_timeout: None,
+            tcp_keepalive: None,
+            http2_keep_alive_interval: None,
+            http_version: HttpVersion::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersion {
+    /// Use HTTP/2 if the server agrees during the TLS handshake (ALPN), otherwise HTTP/1.1.
+    #[default]
+    Negotiate,
+    /// Use only HTTP/1.1.
+    Http1Only,
+    /// Use HTTP/2 without negotiation, including for `http` URLs. The server must support HTTP/2.
+    Http2PriorKnowledge,
+}
@@ -0,0 +1,142 @@
+#[cfg(feature = "reqwest")]
+mod connection;
+#[cfg(feature = "reqwest")]
+mod proxy;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(feature = "reqwest")]
+mod tls;
+
+#[cfg(feature = "reqwest")]
+pub use connection::*;
+#[cfg(feature = "reqwest")]
+pub use proxy::*;
+#[cfg(feature = "reqwest")]
+pub use tls::*;
+
+use crate::{policies::Policy, stream::BytesStream, Headers, Response};
+use bytes::Bytes;
+use std::sync::Arc;
+
+/// How requests are sent at the end of the [`Pipeline`](crate::Pipeline).
+///
+/// The default returns an empty `200 OK` response without sending the request.
+/// To send requests over HTTP, enable the `reqwest` feature with `reqwest_rustls` or `reqwest_native_tls`
+/// and set [`HttpTransportOptions`] e.g., `options.with_transport(HttpTransportOptions::default())`.
+///
+/// Clones, including those in cloned [`ClientOptions`](crate::ClientOptions), share the same HTTP client and connection pool.
+/// All HTTP transports created from default [`HttpTransportOptions`] share a single connection pool.
+#[derive(Clone, Debug)]
+pub struct TransportOptions {
+    inner: TransportOptionsImpl,
+}
+
+#[derive(Clone, Debug)]
+enum TransportOptionsImpl {
+    Bytes(bytes::Bytes),
+    Custom(Arc<dyn Policy>),
+    #[cfg(feature = "reqwest")]
+    Http(Arc<reqwest::ReqwestTransport>),
+}
+
+impl TransportOptions {
+    pub fn new(response_body: impl Into<Bytes>) -> Self {
+        let inner = TransportOptionsImpl::Bytes(response_body.into());
+        Self { inner }
+    }
+
+    /// Sends requests using a custom [`Policy`] e.g., to mock responses or use a different HTTP client.
+    ///
+    /// The policy is called last in the pipeline and must not call the next policy.
+    pub fn new_custom_policy(policy: Arc<dyn Policy>) -> Self {
+        let inner = TransportOptionsImpl::Custom(policy);
+        Self { inner }
+    }
+
+    pub async fn send(
+        &self,
+        ctx: &mut crate::Context,
+        request: &mut crate::Request,
+    ) -> crate::Result<crate::Response> {
+        use TransportOptionsImpl as I;
+        match &self.inner {
+            I::Bytes(bytes) => {
+                let response = Response::new(
+                    200,
+                    Headers::default(),
+                    Box::pin(BytesStream::new(bytes.clone())),
+                );
+                Ok(response)
+            }
+            I::Custom(policy) => policy.send(ctx, request, &[]).await,
+            #[cfg(feature = "reqwest")]
+            I::Http(transport) => transport.send(request).await,
+        }
+    }
+}
+
+impl Default for TransportOptions {
+    fn default() -> Self {
+        Self {
+            inner: TransportOptionsImpl::Bytes(Bytes::new()),
+        }
+    }
+}
+
+/// Options for the HTTP transport.
+///
+/// ```
+/// use azure_core::{HttpTransportOptions, Proxy, ProxyOptions, TransportOptions, Url};
+///
+/// let transport: TransportOptions = HttpTransportOptions {
+///     proxy: ProxyOptions {
+///         https: Some(Proxy::new(Url::parse("http://proxy.contoso.com:8080").unwrap())),
+///         no_proxy: vec![".contoso.com".to_string()],
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// }
+/// .into();
+/// ```
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+pub struct HttpTransportOptions {
+    /// Proxy settings. By default, no proxy is used.
+    pub proxy: ProxyOptions,
+
+    /// TLS settings.
+    pub tls: TlsOptions,
+
+    /// Connection pooling and protocol settings.
+    pub connection: ConnectionOptions,
+}
+
+/// Deserializes [`HttpTransportOptions`] for an HTTP transport.
+///
+/// Settings that match the defaults reuse the shared HTTP client. Any other settings create a new HTTP client
+/// with its own connection pool each time they are deserialized, so load client options once and clone them.
+#[cfg(feature = "reqwest")]
+impl<'de> serde::Deserialize<'de> for TransportOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HttpTransportOptions::deserialize(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<HttpTransportOptions> for TransportOptions {
+    /// Creates an HTTP transport, or reuses the one shared by all transports created from default options.
+    fn from(options: HttpTransportOptions) -> Self {
+        static DEFAULT: std::sync::OnceLock<Arc<reqwest::ReqwestTransport>> =
+            std::sync::OnceLock::new();
+        let transport = match options == HttpTransportOptions::default() {
+            true => DEFAULT
+                .get_or_init(|| Arc::new(reqwest::ReqwestTransport::new(options)))
+                .clone(),
+            false => Arc::new(reqwest::ReqwestTransport::new(options)),
+        };
+        Self {
+            inner: TransportOptionsImpl::Http(transport),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use std::fmt;
+
+/// TLS settings for the HTTP transport.
+///
+/// The TLS implementation is chosen by the `reqwest_rustls` or `reqwest_native_tls` features.
+/// The `reqwest` feature alone enables neither, so `https` requests and any settings other than the defaults fail.
+/// If both are enabled, `reqwest_native_tls` is used.
+/// Only `min_version` is deserialized; certificates must be set in code.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct TlsOptions {
+    /// PEM-encoded certificates to trust in addition to the default root certificates e.g., a private CA.
+    ///
+    /// Each entry may contain more than one certificate.
+    #[serde(skip)]
+    pub root_certificates: Vec<Vec<u8>>,
+
+    /// A client certificate for mutual TLS.
+    #[serde(skip)]
+    pub client_certificate: Option<ClientCertificate>,
+
+    /// The minimum TLS version. The default is TLS 1.2.
+    pub min_version: TlsVersion,
+}
+
+impl TlsOptions {
+    pub(crate) fn is_default(&self) -> bool {
+        self.root_certificates.is_empty()
+            && self.client_certificate.is_none()
+            && self.min_version == TlsVersion::default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsVersion {
+    #[default]
+    Tls1_2,
+    Tls1_3,
+}
+
+/// A PEM-encoded client certificate chain and PKCS #8 private key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ClientCertificate {
+    certificate: Vec<u8>,
+    private_key: Vec<u8>,
+}
+
+impl ClientCertificate {
+    pub fn new(certificate: impl Into<Vec<u8>>, private_key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            certificate: certificate.into(),
+            private_key: private_key.into(),
+        }
+    }
+
+    pub fn certificate(&self) -> &[u8] {
+        &self.certificate
+    }
+
+    pub(crate) fn private_key(&self) -> &[u8] {
+        &self.private_key
+    }
+}
+
+impl fmt::Debug for ClientCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCertificate")
+            .field("certificate", &String::from_utf8_lossy(&self.certificate))
+            .finish_non_exhaustive()
+    }
+}
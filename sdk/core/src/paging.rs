@@ -0,0 +1,252 @@
+use crate::{context::Context, pipeline::Pipeline, request::Request};
+use futures::{future::BoxFuture, Stream};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{ready, Poll},
+};
+
+/// A token to resume a paged list operation from the next page, even in another process.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContinuationToken {
+    /// The absolute URL of the next page returned as `nextLink`.
+    NextLink(String),
+    /// An opaque token for the next page returned as `continuationToken`.
+    Token(String),
+}
+
+/// A page of items returned from a paged list operation.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Page<T> {
+    #[serde(rename = "value", default = "Vec::new")]
+    pub items: Vec<T>,
+    #[serde(rename = "nextLink", alias = "@odata.nextLink")]
+    pub next_link: Option<String>,
+    #[serde(rename = "continuationToken")]
+    pub continuation_token: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Gets the [`ContinuationToken`] for the next page, if any.
+    pub fn continuation(&self) -> Option<ContinuationToken> {
+        if let Some(next_link) = self.next_link.as_ref().filter(|s| !s.is_empty()) {
+            return Some(ContinuationToken::NextLink(next_link.clone()));
+        }
+        self.continuation_token
+            .as_ref()
+            .filter(|s| !s.is_empty())
+            .map(|token| ContinuationToken::Token(token.clone()))
+    }
+}
+
+type RequestFactory = dyn Fn(Option<&ContinuationToken>) -> crate::Result<Request> + Send + Sync;
+
+/// A [`Stream`] of [`Page`]s from a paged list operation.
+pub struct Pages<T> {
+    pipeline: Pipeline,
+    ctx: Context,
+    make_request: Arc<RequestFactory>,
+    continuation: Option<ContinuationToken>,
+    state: State<T>,
+}
+
+enum State<T> {
+    Next,
+    Pending(BoxFuture<'static, crate::Result<Page<T>>>),
+    Done,
+}
+
+impl<T> Pages<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Gets the [`ContinuationToken`] of the page that will be returned next, or `None` if there are no more pages.
+    ///
+    /// Returns `None` before the first page is requested unless the pager was resumed from a [`ContinuationToken`].
+    pub fn continuation_token(&self) -> Option<ContinuationToken> {
+        match self.state {
+            State::Done => None,
+            _ => self.continuation.clone(),
+        }
+    }
+
+    fn fetch(&self) -> BoxFuture<'static, crate::Result<Page<T>>> {
+        let pipeline = self.pipeline.clone();
+        let mut ctx = self.ctx.clone();
+        let request = (self.make_request)(self.continuation.as_ref());
+        Box::pin(async move {
+            let mut request = request?;
+            let response = pipeline.send(&mut ctx, &mut request).await?;
+            response.json().await
+        })
+    }
+}
+
+impl<T> Stream for Pages<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Item = crate::Result<Page<T>>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                State::Next => this.state = State::Pending(this.fetch()),
+                State::Pending(future) => {
+                    let result = ready!(future.as_mut().poll(cx));
+                    return match result {
+                        Ok(page) => {
+                            this.continuation = page.continuation();
+                            this.state = match this.continuation {
+                                Some(_) => State::Next,
+                                None => State::Done,
+                            };
+                            Poll::Ready(Some(Ok(page)))
+                        }
+                        Err(error) => {
+                            this.state = State::Done;
+                            Poll::Ready(Some(Err(error)))
+                        }
+                    };
+                }
+                State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// A [`Stream`] of items from a paged list operation.
+///
+/// Pages are requested as needed through the same [`Pipeline`] and [`Context`] as the initial request.
+/// Call [`Pager::into_pages`] to stream whole pages instead.
+pub struct Pager<T> {
+    pages: Pages<T>,
+    items: std::vec::IntoIter<T>,
+}
+
+impl<T> Pager<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Create a [`Pager`] that calls `make_request` to create the request for each page.
+    ///
+    /// `make_request` is passed `None` for the first page, unless resuming from `continuation_token`,
+    /// and the [`ContinuationToken`] returned from the previous page for every subsequent page.
+    pub fn new<F>(
+        pipeline: Pipeline,
+        ctx: Context,
+        continuation_token: Option<ContinuationToken>,
+        make_request: F,
+    ) -> Self
+    where
+        F: Fn(Option<&ContinuationToken>) -> crate::Result<Request> + Send + Sync + 'static,
+    {
+        Self {
+            pages: Pages {
+                pipeline,
+                ctx,
+                make_request: Arc::new(make_request),
+                continuation: continuation_token,
+                state: State::Next,
+            },
+            items: Vec::new().into_iter(),
+        }
+    }
+
+    /// Gets the [`ContinuationToken`] of the next page, or `None` if there are no more pages.
+    ///
+    /// Resuming from this token starts at the next page; any remaining items of the current page are not returned.
+    pub fn continuation_token(&self) -> Option<ContinuationToken> {
+        self.pages.continuation_token()
+    }
+
+    /// Stream whole pages instead of individual items, starting with the next page.
+    pub fn into_pages(self) -> Pages<T> {
+        self.pages
+    }
+}
+
+impl<T> Stream for Pager<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Item = crate::Result<T>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.items.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            match ready!(Pin::new(&mut this.pages).poll_next(cx)) {
+                Some(Ok(page)) => this.items = page.items.into_iter(),
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+// Items are never pinned.
+impl<T> Unpin for Pager<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::pager;
+    use futures::{StreamExt, TryStreamExt};
+
+    #[tokio::test]
+    async fn streams_items() {
+        let items: Vec<i32> = pager(None).try_collect().await.expect("expected items");
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn streams_pages() {
+        let mut pages = pager(None).into_pages();
+        assert_eq!(pages.continuation_token(), None);
+
+        let page = pages.next().await.expect("page").expect("page");
+        assert_eq!(page.items, [1, 2]);
+        assert_eq!(
+            pages.continuation_token(),
+            Some(ContinuationToken::NextLink(
+                "https://localhost/items/2".to_string()
+            ))
+        );
+
+        let page = pages.next().await.expect("page").expect("page");
+        assert_eq!(page.items, [3]);
+
+        let page = pages.next().await.expect("page").expect("page");
+        assert_eq!(page.items, [4, 5]);
+        assert_eq!(pages.continuation_token(), None);
+        assert!(pages.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn resumes_from_continuation_token() {
+        let mut first = pager(None);
+        assert_eq!(first.next().await.expect("item").expect("item"), 1);
+
+        let token = serde_json::to_string(&first.continuation_token()).expect("serialize");
+        assert_eq!(token, r#"{"nextLink":"https://localhost/items/2"}"#);
+
+        let token: ContinuationToken = serde_json::from_str(&token).expect("deserialize");
+        let items: Vec<i32> = pager(Some(token))
+            .try_collect()
+            .await
+            .expect("expected items");
+        assert_eq!(items, [3, 4, 5]);
+    }
+}
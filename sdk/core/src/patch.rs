@@ -0,0 +1,232 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// A model field for a JSON Merge Patch (RFC 7396) document, distinguishing a missing field from an explicit `null`.
+///
+/// A missing field leaves the resource unchanged, while `null` removes the value from the resource.
+/// Declare fields with `#[serde(default, skip_serializing_if = "Patch::is_absent")]`:
+///
+/// ```
+/// use azure_core::Patch;
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct Properties {
+///     #[serde(default, skip_serializing_if = "Patch::is_absent")]
+///     enabled: Patch<bool>,
+///     #[serde(default, skip_serializing_if = "Patch::is_absent")]
+///     content_type: Patch<String>,
+/// }
+///
+/// let properties = Properties {
+///     enabled: Patch::Value(false),
+///     content_type: Patch::Null,
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&properties).unwrap(),
+///     r#"{"enabled":false,"content_type":null}"#,
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    /// The field is not serialized and the resource is unchanged.
+    #[default]
+    Absent,
+    /// The field is serialized as `null` and removed from the resource.
+    Null,
+    /// The field is serialized and set on the resource.
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    /// Gets the value if set.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Value(value)
+    }
+}
+
+impl<T> Serialize for Patch<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Patch::Value(value) => serializer.serialize_some(value),
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Patch<T>
+where
+    T: Deserialize<'de>,
+{
+    /// Deserializes `null` as [`Patch::Null`]; use `#[serde(default)]` to deserialize a missing field as [`Patch::Absent`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+/// A JSON Patch (RFC 6902) document.
+///
+/// ```
+/// use azure_core::JsonPatch;
+/// use serde_json::json;
+///
+/// let mut patch = JsonPatch::new();
+/// patch
+///     .test("/enabled", true)
+///     .replace("/enabled", false)
+///     .add("/tags/owner", json!("ops"))
+///     .remove("/contentType");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JsonPatch(Vec<JsonPatchOperation>);
+
+impl JsonPatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn operations(&self) -> &[JsonPatchOperation] {
+        &self.0
+    }
+
+    pub fn add(&mut self, path: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.push(JsonPatchOperation::Add {
+            path: path.into(),
+            value: value.into(),
+        })
+    }
+
+    pub fn remove(&mut self, path: impl Into<String>) -> &mut Self {
+        self.push(JsonPatchOperation::Remove { path: path.into() })
+    }
+
+    pub fn replace(&mut self, path: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.push(JsonPatchOperation::Replace {
+            path: path.into(),
+            value: value.into(),
+        })
+    }
+
+    pub fn move_from(&mut self, from: impl Into<String>, path: impl Into<String>) -> &mut Self {
+        self.push(JsonPatchOperation::Move {
+            from: from.into(),
+            path: path.into(),
+        })
+    }
+
+    pub fn copy_from(&mut self, from: impl Into<String>, path: impl Into<String>) -> &mut Self {
+        self.push(JsonPatchOperation::Copy {
+            from: from.into(),
+            path: path.into(),
+        })
+    }
+
+    pub fn test(&mut self, path: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.push(JsonPatchOperation::Test {
+            path: path.into(),
+            value: value.into(),
+        })
+    }
+
+    pub fn push(&mut self, operation: JsonPatchOperation) -> &mut Self {
+        self.0.push(operation);
+        self
+    }
+}
+
+/// An operation in a [`JsonPatch`] document. Paths are JSON Pointers (RFC 6901).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum JsonPatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+    struct Model {
+        #[serde(default, skip_serializing_if = "Patch::is_absent")]
+        enabled: Patch<bool>,
+        #[serde(default, skip_serializing_if = "Patch::is_absent")]
+        tags: Patch<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Patch::is_absent")]
+        content_type: Patch<String>,
+    }
+
+    #[test]
+    fn merge_patch_roundtrip() {
+        let model = Model {
+            enabled: Patch::Value(true),
+            tags: Patch::Null,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&model).expect("serialize");
+        assert_eq!(json, r#"{"enabled":true,"tags":null}"#);
+
+        let deserialized: Model = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(deserialized, model);
+        assert!(deserialized.content_type.is_absent());
+    }
+
+    #[test]
+    fn json_patch() {
+        let mut patch = JsonPatch::new();
+        patch
+            .test("/enabled", true)
+            .replace("/enabled", false)
+            .add("/tags/-", "a")
+            .remove("/contentType")
+            .move_from("/a", "/b")
+            .copy_from("/b", "/c");
+
+        assert_eq!(
+            serde_json::to_value(&patch).expect("serialize"),
+            json!([
+                {"op": "test", "path": "/enabled", "value": true},
+                {"op": "replace", "path": "/enabled", "value": false},
+                {"op": "add", "path": "/tags/-", "value": "a"},
+                {"op": "remove", "path": "/contentType"},
+                {"op": "move", "from": "/a", "path": "/b"},
+                {"op": "copy", "from": "/b", "path": "/c"},
+            ])
+        );
+    }
+}
@@ -2,45 +2,501 @@ use std::sync::Arc;
 
 use crate::{
     context::Context,
+    error::{is_success, Error, ErrorKind, HttpError},
     options::ClientOptions,
-    policies::{Policy, TransportPolicy},
+    policies::{
+        CustomRequestPolicy, FaultInjectionPolicy, Policy, RetryPolicy, TelemetryPolicy,
+        TransportPolicy,
+    },
     request::Request,
     response::Response,
+    runtime::AsyncRuntime,
 };
 
+/// A chain of [`Policy`] implementations that send a [`Request`] and return a [`Response`].
+///
+/// Policies are called in the following order:
+///
+/// 1. Client per-call policies passed to [`Pipeline::new`].
+/// 2. User per-call policies from [`ClientOptions::per_call_policies`].
+/// 3. [`TelemetryPolicy`].
+/// 4. [`RetryPolicy`]; all subsequent policies are called for every attempt.
+/// 5. [`CustomRequestPolicy`] applying headers and query parameters from the [`Context`].
+/// 6. Client per-retry policies passed to [`Pipeline::new`] e.g., authentication.
+/// 7. User per-retry policies from [`ClientOptions::per_retry_policies`].
+/// 8. [`FaultInjectionPolicy`], which does nothing unless faults are configured.
+/// 9. [`TransportPolicy`].
+///
+/// Each policy is a named stage. Use a [`PipelineBuilder`] to insert, replace, or remove stages.
 #[derive(Clone, Debug)]
 pub struct Pipeline {
+    names: Vec<&'static str>,
     pipeline: Vec<Arc<dyn Policy>>,
+    runtime: Arc<dyn AsyncRuntime>,
+    expected_statuses: ExpectedStatuses,
+}
+
+/// Status codes other than 2xx that [`Pipeline::send`] returns as a [`Response`] instead of an error.
+///
+/// Set for a client with [`PipelineBuilder::with_expected_statuses`], or for a single client method call
+/// by inserting them into its [`Context`] e.g., `304 Not Modified` for a conditional request
+/// or `404 Not Found` for a method that checks whether a resource exists.
+/// Statuses of a call are expected in addition to those of the client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedStatuses(Vec<u16>);
+
+impl ExpectedStatuses {
+    pub fn new(statuses: impl IntoIterator<Item = u16>) -> Self {
+        Self(statuses.into_iter().collect())
+    }
+
+    pub fn contains(&self, status: u16) -> bool {
+        self.0.contains(&status)
+    }
+}
+
+impl<const N: usize> From<[u16; N]> for ExpectedStatuses {
+    fn from(statuses: [u16; N]) -> Self {
+        Self::new(statuses)
+    }
 }
 
 impl Pipeline {
+    /// Stage name of client per-call policies passed to [`Pipeline::new`].
+    pub const PER_CALL: &'static str = "per_call";
+    /// Stage name of user per-call policies from [`ClientOptions::per_call_policies`].
+    pub const USER_PER_CALL: &'static str = "user_per_call";
+    pub const TELEMETRY: &'static str = "telemetry";
+    pub const RETRY: &'static str = "retry";
+    pub const CUSTOM_HEADERS: &'static str = "custom_headers";
+    /// Stage name of client per-retry policies passed to [`Pipeline::new`].
+    pub const PER_RETRY: &'static str = "per_retry";
+    /// Stage name of user per-retry policies from [`ClientOptions::per_retry_policies`].
+    pub const USER_PER_RETRY: &'static str = "user_per_retry";
+    pub const FAULT_INJECTION: &'static str = "fault_injection";
+    pub const TRANSPORT: &'static str = "transport";
+
     pub fn new(
-        _crate_name: Option<&'static str>,
-        _crate_version: Option<&'static str>,
+        crate_name: Option<&'static str>,
+        crate_version: Option<&'static str>,
         options: &ClientOptions,
         per_call_policies: Vec<Arc<dyn Policy>>,
         per_retry_policies: Vec<Arc<dyn Policy>>,
     ) -> Self {
-        let mut pipeline: Vec<Arc<dyn Policy>> =
-            Vec::with_capacity(per_call_policies.len() + per_retry_policies.len() + 1);
+        let mut builder = PipelineBuilder::new(crate_name, crate_version, options);
+        builder.insert_all(0, Self::PER_CALL, per_call_policies);
 
-        pipeline.extend_from_slice(&per_call_policies);
+        let index = builder
+            .position(Self::CUSTOM_HEADERS)
+            .expect("custom headers stage")
+            + 1;
+        builder.insert_all(index, Self::PER_RETRY, per_retry_policies);
 
-        // TODO: Telemetry, custom headers, etc. policies.
-        // TODO: Retry policy.
-
-        pipeline.extend_from_slice(&per_retry_policies);
+        builder.build().expect("transport is the last stage")
+    }
 
-        let transport: Arc<dyn Policy> =
-            Arc::new(TransportPolicy::new(options.transport().clone()));
-        pipeline.push(transport);
+    /// Gets the stage names in the order policies are called.
+    pub fn policies(&self) -> &[&'static str] {
+        &self.names
+    }
 
-        Self { pipeline }
+    /// The runtime from [`ClientOptions::runtime`] used e.g., by [`Poller`](crate::Poller) to wait between polls.
+    pub fn runtime(&self) -> &Arc<dyn AsyncRuntime> {
+        &self.runtime
     }
 
+    /// Send the request through the pipeline.
+    ///
+    /// Responses with a status code other than 2xx or [`ExpectedStatuses`] are returned
+    /// as an [`ErrorKind::HttpResponse`](crate::ErrorKind::HttpResponse) error.
     pub async fn send(&self, ctx: &mut Context, request: &mut Request) -> crate::Result<Response> {
-        self.pipeline[0]
+        let response = self.pipeline[0]
             .send(ctx, request, &self.pipeline[1..])
+            .await?;
+
+        let status = response.status();
+        let expected = self.expected_statuses.contains(status)
+            || ctx
+                .value::<ExpectedStatuses>()
+                .is_some_and(|statuses| statuses.contains(status));
+        if !is_success(status) && !expected {
+            return Err(HttpError::new(response).await.into());
+        }
+
+        Ok(response)
+    }
+}
+
+/// Builds a [`Pipeline`] from named stages.
+///
+/// A new builder contains the user per-call policies, [`Pipeline::TELEMETRY`], [`Pipeline::RETRY`],
+/// [`Pipeline::CUSTOM_HEADERS`], the user per-retry policies, [`Pipeline::FAULT_INJECTION`], and [`Pipeline::TRANSPORT`].
+/// Several stages may share a name e.g., [`Pipeline::USER_PER_CALL`].
+///
+/// ```
+/// use azure_core::{policies::CustomHeaderPolicy, ClientOptions, Pipeline, PipelineBuilder};
+/// use std::sync::Arc;
+///
+/// let tenant = Arc::new(CustomHeaderPolicy::new("x-ms-tenant", "contoso"));
+/// let pipeline = PipelineBuilder::new(None, None, &ClientOptions::default())
+///     .insert_after(Pipeline::CUSTOM_HEADERS, "tenant", tenant)?
+///     .remove(Pipeline::TELEMETRY)?
+///     .remove(Pipeline::FAULT_INJECTION)?
+///     .build()?;
+/// assert_eq!(pipeline.policies(), ["retry", "custom_headers", "tenant", "transport"]);
+/// # Ok::<(), azure_core::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PipelineBuilder {
+    stages: Vec<(&'static str, Arc<dyn Policy>)>,
+    runtime: Arc<dyn AsyncRuntime>,
+    expected_statuses: ExpectedStatuses,
+}
+
+impl PipelineBuilder {
+    pub fn new(
+        crate_name: Option<&'static str>,
+        crate_version: Option<&'static str>,
+        options: &ClientOptions,
+    ) -> Self {
+        let mut stages: Vec<(&'static str, Arc<dyn Policy>)> = Vec::with_capacity(
+            options.per_call_policies().len() + options.per_retry_policies().len() + 5,
+        );
+
+        for policy in options.per_call_policies() {
+            stages.push((Pipeline::USER_PER_CALL, policy.clone()));
+        }
+
+        stages.push((
+            Pipeline::TELEMETRY,
+            Arc::new(TelemetryPolicy::new(crate_name, crate_version)),
+        ));
+        stages.push((
+            Pipeline::RETRY,
+            Arc::new(RetryPolicy::new(
+                options.retry().clone(),
+                options.runtime().clone(),
+            )),
+        ));
+        stages.push((
+            Pipeline::CUSTOM_HEADERS,
+            Arc::new(CustomRequestPolicy::new()),
+        ));
+
+        for policy in options.per_retry_policies() {
+            stages.push((Pipeline::USER_PER_RETRY, policy.clone()));
+        }
+
+        stages.push((
+            Pipeline::FAULT_INJECTION,
+            Arc::new(FaultInjectionPolicy::new(
+                options.fault_injection().cloned(),
+                options.runtime().clone(),
+            )),
+        ));
+        stages.push((
+            Pipeline::TRANSPORT,
+            Arc::new(TransportPolicy::new(options.transport().clone())),
+        ));
+
+        Self {
+            stages,
+            runtime: options.runtime().clone(),
+            expected_statuses: ExpectedStatuses::default(),
+        }
+    }
+
+    /// Gets the stage names in the order policies will be called.
+    pub fn policies(&self) -> Vec<&'static str> {
+        self.stages.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Inserts a stage before all other stages.
+    pub fn prepend(&mut self, name: &'static str, policy: Arc<dyn Policy>) -> &mut Self {
+        self.stages.insert(0, (name, policy));
+        self
+    }
+
+    /// Inserts a stage before the first stage named `stage`.
+    pub fn insert_before(
+        &mut self,
+        stage: &str,
+        name: &'static str,
+        policy: Arc<dyn Policy>,
+    ) -> crate::Result<&mut Self> {
+        let index = self.position(stage).ok_or_else(|| not_found(stage))?;
+        self.stages.insert(index, (name, policy));
+        Ok(self)
+    }
+
+    /// Inserts a stage after the last stage named `stage`.
+    pub fn insert_after(
+        &mut self,
+        stage: &str,
+        name: &'static str,
+        policy: Arc<dyn Policy>,
+    ) -> crate::Result<&mut Self> {
+        let index = self
+            .stages
+            .iter()
+            .rposition(|(name, _)| *name == stage)
+            .ok_or_else(|| not_found(stage))?;
+        self.stages.insert(index + 1, (name, policy));
+        Ok(self)
+    }
+
+    /// Replaces the policy of the first stage named `stage`.
+    ///
+    /// Replacing [`Pipeline::TRANSPORT`] swaps the transport; the policy must not call the next policy.
+    pub fn replace(&mut self, stage: &str, policy: Arc<dyn Policy>) -> crate::Result<&mut Self> {
+        let index = self.position(stage).ok_or_else(|| not_found(stage))?;
+        self.stages[index].1 = policy;
+        Ok(self)
+    }
+
+    /// Removes all stages named `stage`.
+    pub fn remove(&mut self, stage: &str) -> crate::Result<&mut Self> {
+        let len = self.stages.len();
+        self.stages.retain(|(name, _)| *name != stage);
+        if self.stages.len() == len {
+            return Err(not_found(stage));
+        }
+        Ok(self)
+    }
+
+    /// Sets status codes other than 2xx that are returned as a [`Response`] for every call.
+    pub fn with_expected_statuses(
+        &mut self,
+        expected_statuses: impl Into<ExpectedStatuses>,
+    ) -> &mut Self {
+        self.expected_statuses = expected_statuses.into();
+        self
+    }
+
+    /// Builds the [`Pipeline`], or returns an error if the last stage is not [`Pipeline::TRANSPORT`].
+    pub fn build(&self) -> crate::Result<Pipeline> {
+        if !matches!(self.stages.last(), Some((name, _)) if *name == Pipeline::TRANSPORT) {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "the last pipeline stage must be the transport",
+            ));
+        }
+
+        let (names, pipeline) = self.stages.iter().cloned().unzip();
+        Ok(Pipeline {
+            names,
+            pipeline,
+            runtime: self.runtime.clone(),
+            expected_statuses: self.expected_statuses.clone(),
+        })
+    }
+
+    fn position(&self, stage: &str) -> Option<usize> {
+        self.stages.iter().position(|(name, _)| *name == stage)
+    }
+
+    fn insert_all(&mut self, index: usize, name: &'static str, policies: Vec<Arc<dyn Policy>>) {
+        self.stages.splice(
+            index..index,
+            policies.into_iter().map(|policy| (name, policy)),
+        );
+    }
+}
+
+fn not_found(stage: &str) -> Error {
+    Error::with_message(ErrorKind::Other, || {
+        format!("pipeline stage {stage} not found")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        headers::USER_AGENT,
+        policies::PolicyResult,
+        testing::{ResponsePolicy, TestOptionsBuilder},
+        ClientOptionsBuilder, TransportOptions, Url,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct RecordPolicy {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for RecordPolicy {
+        async fn send(
+            &self,
+            ctx: &mut Context,
+            request: &mut Request,
+            next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let telemetry = match request.headers().contains(&USER_AGENT) {
+                true => "+telemetry",
+                false => "",
+            };
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}{telemetry}", self.name));
+            next[0].send(ctx, request, &next[1..]).await
+        }
+    }
+
+    #[tokio::test]
+    async fn policy_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let policy = |name| -> Arc<dyn Policy> {
+            Arc::new(RecordPolicy {
+                name,
+                calls: calls.clone(),
+            })
+        };
+
+        let mut builder = TestOptionsBuilder::default();
+        builder
+            .with_per_call_policies(vec![policy("user-call")])
+            .with_per_retry_policies(vec![policy("user-retry")]);
+
+        let pipeline = Pipeline::new(
+            Some("azure_test"),
+            Some("1.0.0"),
+            builder.options(),
+            vec![policy("client-call")],
+            vec![policy("client-retry")],
+        );
+
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+        pipeline
+            .send(&mut Context::default(), &mut request)
+            .await
+            .expect("response");
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                "client-call",
+                "user-call",
+                "client-retry+telemetry",
+                "user-retry+telemetry"
+            ]
+        );
+        assert_eq!(
+            pipeline.policies(),
+            [
+                "per_call",
+                "user_per_call",
+                "telemetry",
+                "retry",
+                "custom_headers",
+                "per_retry",
+                "user_per_retry",
+                "fault_injection",
+                "transport"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn builder_stages() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let policy = |name| -> Arc<dyn Policy> {
+            Arc::new(RecordPolicy {
+                name,
+                calls: calls.clone(),
+            })
+        };
+
+        let mut options = TestOptionsBuilder::default();
+        options
+            .with_per_call_policies(vec![policy("user-call")])
+            .with_transport(TransportOptions::new_custom_policy(Arc::new(
+                ResponsePolicy::statuses([204]),
+            )));
+
+        let mut builder = PipelineBuilder::new(None, None, options.options());
+        builder
+            .insert_after(Pipeline::CUSTOM_HEADERS, "auth", policy("auth"))
+            .expect("insert auth")
+            .insert_before(Pipeline::RETRY, "logging", policy("logging"))
+            .expect("insert logging")
+            .replace(Pipeline::TELEMETRY, policy("telemetry"))
+            .expect("replace telemetry")
+            .remove(Pipeline::USER_PER_CALL)
+            .expect("remove user per-call");
+        assert_eq!(
+            builder.remove("missing").expect_err("missing stage").kind(),
+            &ErrorKind::Other
+        );
+
+        let mut without_transport = builder.clone();
+        without_transport
+            .remove(Pipeline::TRANSPORT)
+            .expect("remove transport");
+        assert!(without_transport.build().is_err());
+        without_transport
+            .insert_after(Pipeline::FAULT_INJECTION, "mock", policy("mock"))
+            .expect("insert mock");
+        assert!(without_transport.build().is_err());
+
+        let pipeline = builder.build().expect("pipeline");
+        assert_eq!(
+            pipeline.policies(),
+            [
+                "telemetry",
+                "logging",
+                "retry",
+                "custom_headers",
+                "auth",
+                "fault_injection",
+                "transport"
+            ]
+        );
+
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+        let response = pipeline
+            .send(&mut Context::default(), &mut request)
+            .await
+            .expect("response");
+        assert_eq!(response.status(), 204);
+        assert_eq!(*calls.lock().unwrap(), ["telemetry", "logging", "auth"]);
+    }
+
+    #[tokio::test]
+    async fn expected_statuses() {
+        let mut options = TestOptionsBuilder::default();
+        options.with_transport(TransportOptions::new_custom_policy(Arc::new(
+            ResponsePolicy::statuses([404; 3]),
+        )));
+        let send = |pipeline: Pipeline, mut ctx: Context| async move {
+            let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+            pipeline.send(&mut ctx, &mut request).await
+        };
+
+        let mut builder = PipelineBuilder::new(None, None, options.options());
+        let error = send(builder.build().unwrap(), Context::default())
+            .await
+            .err()
+            .expect("not found");
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::HttpResponse { status: 404, .. }
+        ));
+
+        let mut ctx = Context::default();
+        ctx.insert(ExpectedStatuses::from([404]));
+        let response = send(builder.build().unwrap(), ctx)
+            .await
+            .expect("call expects 404");
+        assert_eq!(response.status(), 404);
+
+        builder.with_expected_statuses([304, 404]);
+        let response = send(builder.build().unwrap(), Context::default())
             .await
+            .expect("client expects 404");
+        assert_eq!(response.status(), 404);
     }
 }
@@ -0,0 +1,381 @@
+use crate::{
+    context::Context,
+    etag::Etag,
+    headers::{HeaderName, Headers, IF_MATCH, IF_NONE_MATCH},
+    policies::{Policy, PolicyResult},
+    request::Request,
+    response::Response,
+    stream::BytesStream,
+};
+use bytes::Bytes;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+/// Caches `GET` responses with an ETag and revalidates them using `if-none-match`.
+///
+/// When the service responds with `304 Not Modified`, the cached response is returned instead.
+/// Responses are keyed by URL and any headers passed to [`CachePolicy::new`].
+/// Because authorization is not part of the key, do not share a [`ResponseCache`] between clients using different credentials.
+///
+/// This policy is opt-in; add it to [`ClientOptions::per_call_policies`](crate::ClientOptions::per_call_policies).
+#[derive(Clone, Debug)]
+pub struct CachePolicy {
+    cache: Arc<dyn ResponseCache>,
+    key_headers: Vec<HeaderName>,
+}
+
+impl CachePolicy {
+    pub fn new(cache: Arc<dyn ResponseCache>, key_headers: Vec<HeaderName>) -> Self {
+        Self { cache, key_headers }
+    }
+
+    fn key(&self, request: &Request) -> String {
+        let mut key = request.url().to_string();
+        for name in &self.key_headers {
+            if let Some(value) = request.headers().get_optional_str(name) {
+                key.push_str(&format!("\n{}: {value}", name.as_str()));
+            }
+        }
+        key
+    }
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::new(Arc::new(MemoryResponseCache::default()), Vec::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for CachePolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        // Callers sending their own conditions expect the service's response.
+        if request.method() != "GET"
+            || request.headers().contains(&IF_MATCH)
+            || request.headers().contains(&IF_NONE_MATCH)
+        {
+            return next[0].send(ctx, request, &next[1..]).await;
+        }
+
+        let key = self.key(request);
+        let response = match self.cache.get(&key).await {
+            Some(cached) => {
+                // Send a copy so the caller's request is not left with a condition.
+                let mut request = request.clone();
+                request.insert_header(IF_NONE_MATCH, cached.etag().to_string());
+                let response = next[0].send(ctx, &mut request, &next[1..]).await?;
+                if response.status() == 304 {
+                    return Ok(cached.into());
+                }
+                response
+            }
+            None => next[0].send(ctx, request, &next[1..]).await?,
+        };
+
+        match response.status() {
+            200 => {
+                let Some(etag) = response.etag()? else {
+                    self.cache.remove(&key).await;
+                    return Ok(response);
+                };
+                let headers = response.headers().clone();
+                let body = response.into_body().collect().await?;
+                let cached = CachedResponse::new(etag, 200, headers, body);
+                self.cache.insert(key, cached.clone()).await;
+                Ok(cached.into())
+            }
+            404 | 410 => {
+                self.cache.remove(&key).await;
+                Ok(response)
+            }
+            _ => Ok(response),
+        }
+    }
+}
+
+/// Storage for responses cached by a [`CachePolicy`].
+///
+/// Implementations should treat storage failures as cache misses.
+#[async_trait::async_trait]
+pub trait ResponseCache: Send + Sync + std::fmt::Debug {
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    async fn insert(&self, key: String, response: CachedResponse);
+
+    async fn remove(&self, key: &str);
+}
+
+/// A response stored in a [`ResponseCache`].
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    etag: Etag,
+    status: u16,
+    headers: Headers,
+    body: Bytes,
+}
+
+impl CachedResponse {
+    pub fn new(etag: Etag, status: u16, headers: Headers, body: Bytes) -> Self {
+        Self {
+            etag,
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn etag(&self) -> &Etag {
+        &self.etag
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Approximate size in bytes of the headers and body.
+    pub fn size(&self) -> usize {
+        self.headers
+            .iter()
+            .map(|(name, value)| name.as_str().len() + value.as_str().len())
+            .sum::<usize>()
+            + self.body.len()
+    }
+}
+
+impl From<CachedResponse> for Response {
+    fn from(cached: CachedResponse) -> Self {
+        Response::new(
+            cached.status,
+            cached.headers,
+            Box::pin(BytesStream::new(cached.body)),
+        )
+    }
+}
+
+/// Limits for a [`MemoryResponseCache`].
+#[derive(Clone, Debug)]
+pub struct MemoryResponseCacheOptions {
+    /// The maximum number of responses. The default is 1,000.
+    pub max_entries: usize,
+
+    /// The maximum total [`CachedResponse::size`] in bytes. Larger responses are not cached. The default is 16 MiB.
+    pub max_size: usize,
+}
+
+impl Default for MemoryResponseCacheOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: 1_000,
+            max_size: 16 * 1024 * 1024,
+        }
+    }
+}
+
+/// An in-memory [`ResponseCache`] that evicts the least recently used responses.
+#[derive(Debug, Default)]
+pub struct MemoryResponseCache {
+    options: MemoryResponseCacheOptions,
+    inner: Mutex<MemoryResponseCacheInner>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryResponseCacheInner {
+    entries: HashMap<String, (u64, CachedResponse)>,
+    recent: BTreeMap<u64, String>,
+    tick: u64,
+    size: usize,
+}
+
+impl MemoryResponseCacheInner {
+    fn touch(&mut self, key: &str) -> Option<&CachedResponse> {
+        self.tick += 1;
+        let (tick, response) = self.entries.get_mut(key)?;
+        self.recent.remove(tick);
+        self.recent.insert(self.tick, key.to_string());
+        *tick = self.tick;
+        Some(response)
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((tick, response)) = self.entries.remove(key) {
+            self.recent.remove(&tick);
+            self.size -= response.size();
+        }
+    }
+}
+
+impl MemoryResponseCache {
+    pub fn new(options: MemoryResponseCacheOptions) -> Self {
+        Self {
+            options,
+            inner: Mutex::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The total [`CachedResponse::size`] of all responses.
+    pub fn size(&self) -> usize {
+        self.inner.lock().unwrap().size
+    }
+}
+
+#[async_trait::async_trait]
+impl ResponseCache for MemoryResponseCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.inner.lock().unwrap().touch(key).cloned()
+    }
+
+    async fn insert(&self, key: String, response: CachedResponse) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove(&key);
+
+        let size = response.size();
+        if self.options.max_entries == 0 || size > self.options.max_size {
+            return;
+        }
+
+        while inner.entries.len() >= self.options.max_entries
+            || inner.size + size > self.options.max_size
+        {
+            let Some((_, oldest)) = inner.recent.pop_first() else {
+                break;
+            };
+            inner.remove(&oldest);
+        }
+
+        inner.tick += 1;
+        let tick = inner.tick;
+        inner.recent.insert(tick, key.clone());
+        inner.entries.insert(key, (tick, response));
+        inner.size += size;
+    }
+
+    async fn remove(&self, key: &str) {
+        self.inner.lock().unwrap().remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Url;
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        requests: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for MockService {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let if_none_match = request
+                .headers()
+                .get_optional_str(&IF_NONE_MATCH)
+                .map(String::from);
+            let status = match if_none_match.as_deref() {
+                Some("\"1\"") => 304,
+                _ => 200,
+            };
+            self.requests.lock().unwrap().push(if_none_match);
+
+            let mut headers = Headers::new();
+            headers.insert("etag", "\"1\"");
+            let body = if status == 200 { "secret" } else { "" };
+            Ok(Response::new(
+                status,
+                headers,
+                Box::pin(BytesStream::new(body)),
+            ))
+        }
+    }
+
+    fn cached(key: &'static str, body: &'static str) -> CachedResponse {
+        CachedResponse::new(
+            Etag::from(key),
+            200,
+            Headers::new(),
+            Bytes::from_static(body.as_bytes()),
+        )
+    }
+
+    #[tokio::test]
+    async fn serves_not_modified_from_cache() {
+        let service = Arc::new(MockService::default());
+        let next: Vec<Arc<dyn Policy>> = vec![service.clone()];
+        let policy = CachePolicy::default();
+        let url = Url::parse("https://localhost/secrets/name").unwrap();
+
+        for _ in 0..2 {
+            let mut request = Request::new(url.clone(), "GET");
+            let response = policy
+                .send(&mut Context::default(), &mut request, &next)
+                .await
+                .expect("response");
+            assert_eq!(response.status(), 200);
+            assert_eq!(response.into_body().collect().await.unwrap(), "secret");
+            assert!(!request.headers().contains(&IF_NONE_MATCH));
+        }
+
+        let mut request = Request::new(url, "PUT");
+        policy
+            .send(&mut Context::default(), &mut request, &next)
+            .await
+            .expect("response");
+
+        assert_eq!(
+            *service.requests.lock().unwrap(),
+            [None, Some("\"1\"".to_string()), None]
+        );
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used() {
+        let cache = MemoryResponseCache::new(MemoryResponseCacheOptions {
+            max_entries: 2,
+            max_size: 8,
+        });
+        cache.insert("a".into(), cached("a", "aa")).await;
+        cache.insert("b".into(), cached("b", "bb")).await;
+        assert!(cache.get("a").await.is_some());
+
+        cache.insert("c".into(), cached("c", "cc")).await;
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("b").await.is_none());
+
+        cache.insert("d".into(), cached("d", "dddddd")).await;
+        assert_eq!(cache.size(), 8);
+        assert!(cache.get("a").await.is_none());
+        assert!(cache.get("c").await.is_some());
+
+        cache.insert("e".into(), cached("e", "eeeeeeeee")).await;
+        assert!(cache.get("e").await.is_none());
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    context::Context,
+    error::{is_transient_status, Error, ErrorKind},
+    policies::{Policy, PolicyResult},
+    request::Request,
+};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Called with the host, previous state, and new state when a circuit changes state.
+pub type CircuitStateCallback = Arc<dyn Fn(&str, CircuitState, CircuitState) + Send + Sync>;
+
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerOptions {
+    /// The number of consecutive transient failures that opens the circuit. The default is 5.
+    pub failure_threshold: u32,
+
+    /// How long the circuit stays open before a probe request is sent. The default is 30 seconds.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerOptions {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent normally.
+    Closed,
+    /// Requests fail immediately with [`ErrorKind::CircuitOpen`].
+    Open,
+    /// A single probe request is sent to decide whether to close or reopen the circuit.
+    HalfOpen,
+}
+
+/// Stops sending requests to a host after consecutive transient failures.
+///
+/// Timeouts, connection failures, and transient status codes other than `429 Too Many Requests` count as failures.
+/// While the circuit for a host is open, requests fail with [`ErrorKind::CircuitOpen`] which is not retried.
+/// After [`CircuitBreakerOptions::cooldown`] one probe request is sent; if it succeeds the circuit closes.
+///
+/// Add this policy to [`ClientOptions::per_retry_policies`](crate::ClientOptions::per_retry_policies) so that every attempt counts.
+/// Clones share circuits.
+#[derive(Clone)]
+pub struct CircuitBreakerPolicy {
+    options: CircuitBreakerOptions,
+    circuits: Arc<Mutex<HashMap<String, Circuit>>>,
+    callbacks: Vec<CircuitStateCallback>,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    failures: u32,
+    changed: Instant,
+}
+
+impl Default for Circuit {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            failures: 0,
+            changed: Instant::now(),
+        }
+    }
+}
+
+impl CircuitBreakerPolicy {
+    pub fn new(options: CircuitBreakerOptions) -> Self {
+        Self {
+            options,
+            circuits: Arc::default(),
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Adds a callback called whenever a circuit changes state.
+    pub fn with_state_callback(
+        &mut self,
+        callback: impl Fn(&str, CircuitState, CircuitState) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.callbacks.push(Arc::new(callback));
+        self
+    }
+
+    /// Gets the state of the circuit for `host`.
+    pub fn state(&self, host: &str) -> CircuitState {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(host)
+            .map_or(CircuitState::Closed, |circuit| circuit.state)
+    }
+
+    /// Checks whether a request may be sent to `host`.
+    fn acquire(&self, host: &str) -> crate::Result<()> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        match circuit.state {
+            CircuitState::Closed => Ok(()),
+            // A probe is allowed after each cooldown in case an earlier probe was dropped.
+            _ if circuit.changed.elapsed() >= self.options.cooldown => {
+                let from = circuit.state;
+                circuit.state = CircuitState::HalfOpen;
+                circuit.changed = Instant::now();
+                drop(circuits);
+                self.notify(host, from, CircuitState::HalfOpen);
+                Ok(())
+            }
+            _ => Err(Error::with_message(ErrorKind::CircuitOpen, || {
+                format!("circuit for {host} is open")
+            })),
+        }
+    }
+
+    fn record(&self, host: &str, failed: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        let from = circuit.state;
+        let to = match failed {
+            false => {
+                circuit.failures = 0;
+                CircuitState::Closed
+            }
+            true => {
+                circuit.failures = circuit.failures.saturating_add(1);
+                match from {
+                    CircuitState::Closed if circuit.failures < self.options.failure_threshold => {
+                        CircuitState::Closed
+                    }
+                    _ => CircuitState::Open,
+                }
+            }
+        };
+        if from == to {
+            return;
+        }
+
+        circuit.state = to;
+        circuit.changed = Instant::now();
+        drop(circuits);
+        self.notify(host, from, to);
+    }
+
+    fn notify(&self, host: &str, from: CircuitState, to: CircuitState) {
+        for callback in &self.callbacks {
+            callback(host, from, to);
+        }
+    }
+}
+
+impl Default for CircuitBreakerPolicy {
+    fn default() -> Self {
+        Self::new(CircuitBreakerOptions::default())
+    }
+}
+
+impl Debug for CircuitBreakerPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreakerPolicy")
+            .field("options", &self.options)
+            .field("circuits", &self.circuits)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for CircuitBreakerPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        self.acquire(&host)?;
+
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        let failed = match &result {
+            Ok(response) => response.status() != 429 && is_transient_status(response.status()),
+            Err(error) => error.is_transient(),
+        };
+        self.record(&host, failed);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        options::{FixedRetryOptions, RetryOptions},
+        policies::RetryPolicy,
+        runtime::StdRuntime,
+        testing::ResponsePolicy,
+        Url,
+    };
+
+    async fn send(
+        policy: &CircuitBreakerPolicy,
+        url: &str,
+        statuses: &Arc<ResponsePolicy>,
+    ) -> crate::Result<u16> {
+        let next: Vec<Arc<dyn Policy>> = vec![statuses.clone()];
+        let mut request = Request::new(Url::parse(url).unwrap(), "GET");
+        policy
+            .send(&mut Context::default(), &mut request, &next)
+            .await
+            .map(|response| response.status())
+    }
+
+    #[tokio::test]
+    async fn opens_after_failures() {
+        let policy = CircuitBreakerPolicy::new(CircuitBreakerOptions {
+            failure_threshold: 2,
+            ..Default::default()
+        });
+        let statuses = Arc::new(ResponsePolicy::statuses([503, 500, 200]));
+
+        send(&policy, "https://a.localhost", &statuses)
+            .await
+            .unwrap();
+        assert_eq!(policy.state("a.localhost"), CircuitState::Closed);
+        send(&policy, "https://a.localhost", &statuses)
+            .await
+            .unwrap();
+        assert_eq!(policy.state("a.localhost"), CircuitState::Open);
+
+        let error = send(&policy, "https://a.localhost", &statuses)
+            .await
+            .expect_err("circuit open");
+        assert_eq!(error.kind(), &ErrorKind::CircuitOpen);
+        assert!(!error.is_retryable());
+
+        // Other hosts are unaffected.
+        assert_eq!(
+            send(&policy, "https://b.localhost", &statuses)
+                .await
+                .unwrap(),
+            200
+        );
+    }
+
+    #[tokio::test]
+    async fn probes_after_cooldown() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let mut policy = CircuitBreakerPolicy::new(CircuitBreakerOptions {
+            failure_threshold: 1,
+            cooldown: Duration::ZERO,
+        });
+        {
+            let changes = changes.clone();
+            policy.with_state_callback(move |host, from, to| {
+                changes.lock().unwrap().push((host.to_string(), from, to));
+            });
+        }
+        let statuses = Arc::new(ResponsePolicy::statuses([503, 503, 200]));
+
+        send(&policy, "https://localhost", &statuses).await.unwrap();
+        send(&policy, "https://localhost", &statuses).await.unwrap();
+        send(&policy, "https://localhost", &statuses).await.unwrap();
+
+        use CircuitState::*;
+        let host = String::from("localhost");
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [
+                (host.clone(), Closed, Open),
+                (host.clone(), Open, HalfOpen),
+                (host.clone(), HalfOpen, Open),
+                (host.clone(), Open, HalfOpen),
+                (host, HalfOpen, Closed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_retries_when_open() {
+        let breaker: Arc<dyn Policy> = Arc::new(CircuitBreakerPolicy::new(CircuitBreakerOptions {
+            failure_threshold: 2,
+            ..Default::default()
+        }));
+        let statuses = Arc::new(ResponsePolicy::statuses([503, 503, 200]));
+        let retry = RetryPolicy::new(
+            RetryOptions::fixed(FixedRetryOptions {
+                delay: Duration::ZERO,
+                max_retries: 3,
+                ..Default::default()
+            }),
+            Arc::new(StdRuntime),
+        );
+        let next: Vec<Arc<dyn Policy>> = vec![breaker, statuses.clone()];
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let result = retry
+            .send(&mut Context::default(), &mut request, &next)
+            .await;
+        assert!(result.is_err_and(|error| error.kind() == &ErrorKind::CircuitOpen));
+        assert_eq!(statuses.remaining(), 1);
+    }
+}
@@ -1,11 +1,19 @@
 use crate::{
     context::Context,
-    headers::{HeaderName, HeaderValue},
+    headers::{HeaderName, HeaderValue, Headers},
     policies::{Policy, PolicyResult},
     request::Request,
 };
 use std::sync::Arc;
 
+/// Custom headers stored in a [`Context`] by [`ClientMethodOptionsBuilder::with_header`](crate::ClientMethodOptionsBuilder::with_header).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CustomHeaders(pub(crate) Headers);
+
+/// Custom query parameters stored in a [`Context`] by [`ClientMethodOptionsBuilder::with_query`](crate::ClientMethodOptionsBuilder::with_query).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CustomQueryParameters(pub(crate) Vec<(String, String)>);
+
 #[derive(Clone, Debug)]
 pub struct CustomHeaderPolicy {
     name: String,
@@ -36,3 +44,111 @@ impl Policy for CustomHeaderPolicy {
         next[0].send(ctx, request, &next[1..]).await
     }
 }
+
+/// Applies custom headers and query parameters from the [`Context`] to every attempt of a request.
+///
+/// Custom headers and query parameters replace any with the same name set by the client.
+#[derive(Clone, Debug, Default)]
+pub struct CustomRequestPolicy {}
+
+impl CustomRequestPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for CustomRequestPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if let Some(CustomHeaders(headers)) = ctx.value::<CustomHeaders>() {
+            for (name, value) in headers.iter() {
+                request.insert_header::<HeaderName, HeaderValue>(name.clone(), value.clone());
+            }
+        }
+
+        if let Some(CustomQueryParameters(parameters)) = ctx.value::<CustomQueryParameters>() {
+            let pairs: Vec<(String, String)> = request
+                .url
+                .query_pairs()
+                .into_owned()
+                .filter(|(k, _)| !parameters.iter().any(|(name, _)| name == k))
+                .collect();
+            request
+                .url
+                .query_pairs_mut()
+                .clear()
+                .extend_pairs(pairs)
+                .extend_pairs(parameters);
+        }
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stream::BytesStream, testing::TestMethodOptionsBuilder, ClientMethodOptionsBuilder,
+        Response, Url,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct CapturePolicy(Mutex<Option<Request>>);
+
+    #[async_trait::async_trait]
+    impl Policy for CapturePolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            *self.0.lock().unwrap() = Some(request.clone());
+            Ok(Response::new(
+                200,
+                Headers::new(),
+                Box::pin(BytesStream::new("")),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn applies_headers_and_query() {
+        let mut builder = TestMethodOptionsBuilder::default();
+        builder
+            .with_header("x-ms-preview", "true")
+            .with_query("api-version", "7.6-preview.1")
+            .with_query("trace", "1");
+        let mut ctx = builder.options().context().clone();
+
+        let capture = Arc::new(CapturePolicy::default());
+        let next: Vec<Arc<dyn Policy>> = vec![capture.clone()];
+        let mut request = Request::new(
+            Url::parse("https://localhost/secrets?api-version=7.5").unwrap(),
+            "GET",
+        );
+        CustomRequestPolicy::new()
+            .send(&mut ctx, &mut request, &next)
+            .await
+            .expect("response");
+
+        let request = capture.0.lock().unwrap().take().expect("request");
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("x-ms-preview")),
+            Some("true")
+        );
+        assert_eq!(
+            request.url().as_str(),
+            "https://localhost/secrets?api-version=7.6-preview.1&trace=1"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    context::Context,
+    error::{Error, ErrorKind},
+    headers::{Headers, RETRY_AFTER, RETRY_AFTER_MS},
+    policies::{Policy, PolicyResult},
+    request::Request,
+    response::Response,
+    runtime::AsyncRuntime,
+    stream::BytesStream,
+};
+use futures::{stream, StreamExt};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A failure injected by the [`FaultInjectionPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// Waits before sending the request.
+    Latency(Duration),
+    /// Fails with [`ErrorKind::Connection`] without sending the request, as if the connection was reset.
+    ConnectionReset,
+    /// Returns a response with `status` without sending the request, with `retry-after` headers if set.
+    Status {
+        status: u16,
+        retry_after: Option<Duration>,
+    },
+    /// Sends the request but fails with [`ErrorKind::Io`] after reading `len` bytes of a longer response body.
+    TruncatedBody(usize),
+}
+
+/// Which faults the [`FaultInjectionPolicy`] injects.
+///
+/// Set for a client with [`ClientOptionsBuilder::with_fault_injection`](crate::ClientOptionsBuilder::with_fault_injection),
+/// or for a single client method call by inserting options into its [`Context`].
+#[derive(Clone, Debug, Default)]
+pub struct FaultInjectionOptions {
+    /// Faults for consecutive attempts, repeating from the start; `None` sends the attempt unchanged.
+    ///
+    /// When not empty, [`probabilities`](Self::probabilities) are ignored.
+    pub schedule: Vec<Option<Fault>>,
+
+    /// Faults injected at random, each with a probability between 0 and 1. At most one fault is injected per attempt.
+    pub probabilities: Vec<(f64, Fault)>,
+
+    /// Seeds random faults so that a run can be reproduced.
+    pub seed: Option<u64>,
+}
+
+impl FaultInjectionOptions {
+    /// Injects `faults` for consecutive attempts.
+    pub fn schedule(faults: impl IntoIterator<Item = Option<Fault>>) -> Self {
+        Self {
+            schedule: faults.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Injects faults into requests to test how an application reacts to service failures.
+///
+/// This policy runs just before [`Pipeline::TRANSPORT`](crate::Pipeline::TRANSPORT) and does nothing
+/// unless [`FaultInjectionOptions`] are set for the client or in the [`Context`] of a call.
+/// Options in the [`Context`] take precedence, and their schedule starts from the first attempt of that call.
+#[derive(Clone, Debug)]
+pub struct FaultInjectionPolicy {
+    options: Option<FaultInjectionOptions>,
+    state: Arc<Mutex<FaultState>>,
+    runtime: Arc<dyn AsyncRuntime>,
+}
+
+#[derive(Clone, Debug)]
+struct FaultState {
+    attempt: usize,
+    rng: fastrand::Rng,
+}
+
+impl FaultState {
+    fn new(seed: Option<u64>) -> Self {
+        Self {
+            attempt: 0,
+            rng: seed.map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed),
+        }
+    }
+
+    fn next(&mut self, options: &FaultInjectionOptions) -> Option<Fault> {
+        if !options.schedule.is_empty() {
+            let fault = options.schedule[self.attempt % options.schedule.len()].clone();
+            self.attempt += 1;
+            return fault;
+        }
+
+        let mut draw = self.rng.f64();
+        for (probability, fault) in &options.probabilities {
+            if draw < *probability {
+                return Some(fault.clone());
+            }
+            draw -= probability;
+        }
+        None
+    }
+}
+
+impl FaultInjectionPolicy {
+    pub fn new(options: Option<FaultInjectionOptions>, runtime: Arc<dyn AsyncRuntime>) -> Self {
+        let state = FaultState::new(options.as_ref().and_then(|options| options.seed));
+        Self {
+            options,
+            state: Arc::new(Mutex::new(state)),
+            runtime,
+        }
+    }
+
+    fn next_fault(&self, ctx: &mut Context) -> Option<Fault> {
+        if let Some(options) = ctx.value::<FaultInjectionOptions>().cloned() {
+            let mut state = ctx
+                .value::<FaultState>()
+                .cloned()
+                .unwrap_or_else(|| FaultState::new(options.seed));
+            let fault = state.next(&options);
+            ctx.insert(state);
+            return fault;
+        }
+
+        let options = self.options.as_ref()?;
+        self.state.lock().unwrap().next(options)
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for FaultInjectionPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        match self.next_fault(ctx) {
+            None => next[0].send(ctx, request, &next[1..]).await,
+            Some(Fault::Latency(delay)) => {
+                self.runtime.sleep(delay).await;
+                next[0].send(ctx, request, &next[1..]).await
+            }
+            Some(Fault::ConnectionReset) => Err(Error::message(
+                ErrorKind::Connection,
+                "connection reset by fault injection",
+            )),
+            Some(Fault::Status {
+                status,
+                retry_after,
+            }) => {
+                let mut headers = Headers::new();
+                if let Some(retry_after) = retry_after {
+                    headers.insert(RETRY_AFTER_MS, retry_after.as_millis().to_string());
+                    headers.insert(RETRY_AFTER, retry_after.as_secs_f64().ceil().to_string());
+                }
+                Ok(Response::new(
+                    status,
+                    headers,
+                    Box::pin(BytesStream::new("")),
+                ))
+            }
+            Some(Fault::TruncatedBody(len)) => {
+                let response = next[0].send(ctx, request, &next[1..]).await?;
+                let status = response.status();
+                let headers = response.headers().clone();
+                let body = response.into_body().into_stream();
+                Ok(Response::new(
+                    status,
+                    headers,
+                    Box::pin(truncate(body, len)),
+                ))
+            }
+        }
+    }
+}
+
+/// Yields at most `len` bytes from `body`, then an error if any bytes were dropped.
+fn truncate(
+    body: crate::response::PinnedStream,
+    len: usize,
+) -> impl futures::Stream<Item = crate::Result<bytes::Bytes>> + Send + Sync {
+    let truncated = || {
+        Err(Error::message(
+            ErrorKind::Io,
+            "response body truncated by fault injection",
+        ))
+    };
+    stream::unfold(Some((body, len, false)), move |state| async move {
+        let (mut body, remaining, dropped) = state?;
+        if dropped {
+            return Some((truncated(), None));
+        }
+        match body.next().await {
+            Some(Ok(bytes)) if bytes.len() <= remaining => {
+                let remaining = remaining - bytes.len();
+                Some((Ok(bytes), Some((body, remaining, false))))
+            }
+            Some(Ok(_)) if remaining == 0 => Some((truncated(), None)),
+            Some(Ok(bytes)) => {
+                // Return the remaining bytes now and the error on the next poll.
+                Some((Ok(bytes.slice(..remaining)), Some((body, 0, true))))
+            }
+            Some(Err(error)) => Some((Err(error), None)),
+            None => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policies::retry_after, runtime::StdRuntime, Url};
+
+    #[derive(Debug)]
+    struct BodyPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for BodyPolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            Ok(Response::new(
+                200,
+                Headers::new(),
+                Box::pin(BytesStream::new("hello world")),
+            ))
+        }
+    }
+
+    async fn send(policy: &FaultInjectionPolicy, ctx: &mut Context) -> PolicyResult {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(BodyPolicy)];
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+        policy.send(ctx, &mut request, &next).await
+    }
+
+    #[tokio::test]
+    async fn injects_scheduled_faults() {
+        let policy = FaultInjectionPolicy::new(
+            Some(FaultInjectionOptions::schedule([
+                Some(Fault::Status {
+                    status: 429,
+                    retry_after: Some(Duration::from_millis(1500)),
+                }),
+                Some(Fault::ConnectionReset),
+                None,
+            ])),
+            Arc::new(StdRuntime),
+        );
+        let ctx = &mut Context::default();
+
+        let response = send(&policy, ctx).await.unwrap();
+        assert_eq!(response.status(), 429);
+        assert_eq!(response.headers().get_str(&RETRY_AFTER).unwrap(), "2");
+        assert_eq!(
+            retry_after(response.headers()),
+            Some(Duration::from_millis(1500))
+        );
+
+        let error = send(&policy, ctx).await.err().expect("connection reset");
+        assert_eq!(error.kind(), &ErrorKind::Connection);
+
+        assert_eq!(send(&policy, ctx).await.unwrap().status(), 200);
+        assert_eq!(send(&policy, ctx).await.unwrap().status(), 429);
+    }
+
+    #[tokio::test]
+    async fn truncates_body() {
+        let policy = FaultInjectionPolicy::new(None, Arc::new(StdRuntime));
+        let mut ctx = Context::default();
+        ctx.insert(FaultInjectionOptions::schedule([Some(
+            Fault::TruncatedBody(5),
+        )]));
+
+        let mut body = send(&policy, &mut ctx)
+            .await
+            .unwrap()
+            .into_body()
+            .into_stream();
+        assert_eq!(body.next().await.unwrap().unwrap(), "hello");
+        let error = body.next().await.unwrap().expect_err("truncated");
+        assert_eq!(error.kind(), &ErrorKind::Io);
+        assert!(body.next().await.is_none());
+
+        // A body no longer than `len` is returned without an error.
+        let mut ctx = Context::default();
+        ctx.insert(FaultInjectionOptions::schedule([Some(
+            Fault::TruncatedBody(11),
+        )]));
+        let response = send(&policy, &mut ctx).await.unwrap();
+        assert_eq!(response.into_body().collect().await.unwrap(), "hello world");
+
+        // Without options the request is sent unchanged.
+        let response = send(&policy, &mut Context::default()).await.unwrap();
+        assert_eq!(response.into_body().collect().await.unwrap(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn injects_random_faults_from_seed() {
+        let options = FaultInjectionOptions {
+            probabilities: vec![(0.5, Fault::ConnectionReset)],
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut runs = Vec::new();
+        for _ in 0..2 {
+            let policy = FaultInjectionPolicy::new(Some(options.clone()), Arc::new(StdRuntime));
+            let mut failed = Vec::new();
+            for _ in 0..20 {
+                failed.push(send(&policy, &mut Context::default()).await.is_err());
+            }
+            runs.push(failed);
+        }
+
+        assert_eq!(runs[0], runs[1]);
+        assert!(runs[0].contains(&true) && runs[0].contains(&false));
+    }
+}
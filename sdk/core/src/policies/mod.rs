@@ -2,11 +2,23 @@ use crate::{context::Context, request::Request, response::Response};
 use std::sync::Arc;
 
 mod auth;
+mod cache;
+mod circuit_breaker;
 mod custom_header;
+mod fault_injection;
+mod rate_limit;
+mod retry;
+mod telemetry;
 mod transport;
 
 pub use auth::*;
+pub use cache::*;
+pub use circuit_breaker::*;
 pub use custom_header::*;
+pub use fault_injection::*;
+pub use rate_limit::*;
+pub use retry::*;
+pub use telemetry::*;
 pub use transport::*;
 
 pub type PolicyResult = crate::error::Result<Response>;
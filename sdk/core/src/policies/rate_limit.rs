@@ -0,0 +1,311 @@
+use crate::{
+    context::Context,
+    error::{Error, ErrorKind},
+    headers::Headers,
+    policies::{retry_after, Policy, PolicyResult},
+    request::Request,
+    runtime::AsyncRuntime,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+const RATELIMIT_REMAINING_PREFIX: &str = "x-ms-ratelimit-remaining-";
+
+/// How long to pause after a `429 Too Many Requests` response without a `retry-after` header.
+const DEFAULT_PAUSE: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug)]
+pub struct RateLimitOptions {
+    /// The sustained rate of requests per second. The default is 10.
+    pub requests_per_second: f64,
+
+    /// The maximum number of requests sent without waiting after a period of inactivity. The default is 10.
+    pub burst: u32,
+
+    /// The longest a request will wait to be sent before failing with [`ErrorKind::Throttled`]. The default waits indefinitely.
+    pub max_wait: Option<Duration>,
+
+    /// The longest all requests pause after the service throttles a request, whatever its `retry-after`. The default is 60 seconds.
+    pub max_pause: Duration,
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 10.0,
+            burst: 10,
+            max_wait: None,
+            max_pause: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Limits the rate of requests using a token bucket shared by every request sent through this policy and its clones.
+///
+/// When the service responds with `429 Too Many Requests`, all requests pause for the `retry-after` period
+/// and the rate is halved, then recovers gradually as requests succeed.
+/// The rate also slows when `x-ms-ratelimit-remaining-*` headers report fewer remaining requests than the burst.
+///
+/// This policy is opt-in; add it to [`ClientOptions::per_retry_policies`](crate::ClientOptions::per_retry_policies)
+/// so that retries are also limited, and share a clone between clients that call the same service.
+#[derive(Clone, Debug)]
+pub struct RateLimitPolicy {
+    options: RateLimitOptions,
+    runtime: Arc<dyn AsyncRuntime>,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    rate: f64,
+    updated: Instant,
+    paused_until: Instant,
+}
+
+impl RateLimitPolicy {
+    /// Creates a [`RateLimitPolicy`], or returns an error if `requests_per_second` is not a positive number or `burst` is 0.
+    pub fn new(options: RateLimitOptions, runtime: Arc<dyn AsyncRuntime>) -> crate::Result<Self> {
+        if !(options.requests_per_second.is_finite() && options.requests_per_second > 0.0) {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "requests per second must be a positive number: {}",
+                    options.requests_per_second
+                )
+            }));
+        }
+        if options.burst == 0 {
+            return Err(Error::message(ErrorKind::Other, "burst must be at least 1"));
+        }
+
+        let now = Instant::now();
+        let bucket = Bucket {
+            tokens: options.burst as f64,
+            rate: options.requests_per_second,
+            updated: now,
+            paused_until: now,
+        };
+        Ok(Self {
+            options,
+            runtime,
+            bucket: Arc::new(Mutex::new(bucket)),
+        })
+    }
+
+    /// Reserves a token and returns how long to wait before sending.
+    fn reserve(&self) -> crate::Result<Duration> {
+        let now = Instant::now();
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket, now);
+
+        bucket.tokens -= 1.0;
+        let wait = match bucket.tokens < 0.0 {
+            true => Duration::from_secs_f64(-bucket.tokens / bucket.rate),
+            false => Duration::ZERO,
+        }
+        .max(bucket.paused_until.saturating_duration_since(now));
+
+        if let Some(max_wait) = self.options.max_wait {
+            if wait > max_wait {
+                bucket.tokens += 1.0;
+                return Err(Error::with_message(ErrorKind::Throttled, || {
+                    format!("request would wait {wait:?} for the client rate limit")
+                }));
+            }
+        }
+
+        Ok(wait)
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * bucket.rate).min(self.options.burst as f64);
+        bucket.updated = now;
+    }
+
+    /// Adapts the rate to the service's response.
+    fn update(&self, status: u16, headers: &Headers) {
+        let remaining = headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with(RATELIMIT_REMAINING_PREFIX))
+            .filter_map(|(_, value)| value.as_str().parse::<u64>().ok())
+            .min();
+
+        let now = Instant::now();
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket, now);
+
+        let min_rate = self.options.requests_per_second / 16.0;
+        if status == 429 || remaining == Some(0) {
+            let pause = match status {
+                429 => retry_after(headers).unwrap_or(DEFAULT_PAUSE),
+                _ => DEFAULT_PAUSE,
+            }
+            .min(self.options.max_pause);
+            if let Some(paused_until) = now.checked_add(pause) {
+                bucket.paused_until = bucket.paused_until.max(paused_until);
+            }
+            bucket.tokens = bucket.tokens.min(0.0);
+            bucket.rate = (bucket.rate / 2.0).max(min_rate);
+            return;
+        }
+
+        if let Some(remaining) = remaining {
+            if remaining < self.options.burst as u64 {
+                bucket.tokens = bucket.tokens.min(remaining as f64);
+                return;
+            }
+        }
+
+        if (200..300).contains(&status) {
+            bucket.rate = (bucket.rate + self.options.requests_per_second / 10.0)
+                .min(self.options.requests_per_second);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for RateLimitPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let wait = self.reserve()?;
+        if !wait.is_zero() {
+            self.runtime.sleep(wait).await;
+        }
+
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        self.update(response.status(), response.headers());
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        headers::RETRY_AFTER,
+        runtime::StdRuntime,
+        testing::{RecordRuntime, ResponsePolicy},
+        Url,
+    };
+
+    async fn send(policy: &RateLimitPolicy, responses: Vec<(u16, Headers)>) -> crate::Result<()> {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(ResponsePolicy::new(responses))];
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+        policy
+            .send(&mut Context::default(), &mut request, &next)
+            .await
+            .map(|_| ())
+    }
+
+    fn policy(options: RateLimitOptions) -> (RateLimitPolicy, Arc<RecordRuntime>) {
+        let runtime = Arc::new(RecordRuntime::default());
+        let policy = RateLimitPolicy::new(options, runtime.clone()).expect("valid options");
+        (policy, runtime)
+    }
+
+    #[tokio::test]
+    async fn waits_after_burst() {
+        let (policy, runtime) = policy(RateLimitOptions {
+            requests_per_second: 2.0,
+            burst: 2,
+            ..Default::default()
+        });
+        for _ in 0..3 {
+            send(&policy, vec![(200, Headers::new())]).await.unwrap();
+        }
+
+        let sleeps = runtime.sleeps();
+        assert_eq!(sleeps.len(), 1);
+        assert!(sleeps[0] > Duration::from_millis(400) && sleeps[0] <= Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn pauses_after_too_many_requests() {
+        let (policy, runtime) = policy(RateLimitOptions::default());
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "5");
+        send(&policy, vec![(429, headers)]).await.unwrap();
+        assert_eq!(policy.bucket.lock().unwrap().rate, 5.0);
+
+        // Clones share the same bucket.
+        send(&policy.clone(), vec![(200, Headers::new())])
+            .await
+            .unwrap();
+
+        let sleeps = runtime.sleeps();
+        assert!(sleeps[0] > Duration::from_secs(4) && sleeps[0] <= Duration::from_secs(5));
+
+        // Successful responses gradually restore the rate.
+        assert_eq!(policy.bucket.lock().unwrap().rate, 6.0);
+    }
+
+    #[tokio::test]
+    async fn slows_when_remaining_is_low() {
+        let (policy, runtime) = policy(RateLimitOptions {
+            requests_per_second: 1.0,
+            burst: 10,
+            ..Default::default()
+        });
+        let mut headers = Headers::new();
+        headers.insert("x-ms-ratelimit-remaining-subscription-reads", "1");
+        send(&policy, vec![(200, headers)]).await.unwrap();
+        send(&policy, vec![(200, Headers::new())]).await.unwrap();
+        assert!(runtime.sleeps().is_empty());
+
+        send(&policy, vec![(200, Headers::new())]).await.unwrap();
+        assert_eq!(runtime.sleeps().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fails_when_wait_exceeds_max() {
+        let (policy, _) = policy(RateLimitOptions {
+            requests_per_second: 1.0,
+            burst: 1,
+            max_wait: Some(Duration::from_millis(100)),
+            ..Default::default()
+        });
+        send(&policy, vec![(200, Headers::new())]).await.unwrap();
+
+        let error = send(&policy, Vec::new()).await.expect_err("throttled");
+        assert_eq!(error.kind(), &ErrorKind::Throttled);
+    }
+
+    #[tokio::test]
+    async fn caps_pause() {
+        let (policy, runtime) = policy(RateLimitOptions {
+            max_pause: Duration::from_secs(2),
+            ..Default::default()
+        });
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, u64::MAX.to_string());
+        send(&policy, vec![(429, headers)]).await.unwrap();
+        send(&policy, vec![(200, Headers::new())]).await.unwrap();
+
+        let sleeps = runtime.sleeps();
+        assert!(sleeps[0] > Duration::from_secs(1) && sleeps[0] <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        for requests_per_second in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let options = RateLimitOptions {
+                requests_per_second,
+                ..Default::default()
+            };
+            assert!(RateLimitPolicy::new(options, Arc::new(StdRuntime)).is_err());
+        }
+
+        let options = RateLimitOptions {
+            burst: 0,
+            ..Default::default()
+        };
+        assert!(RateLimitPolicy::new(options, Arc::new(StdRuntime)).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    context::Context,
+    error::is_transient_status,
+    headers::{Headers, RETRY_AFTER, RETRY_AFTER_MS, X_MS_RETRY_AFTER_MS},
+    options::RetryOptions,
+    policies::{Policy, PolicyResult},
+    request::Request,
+    runtime::AsyncRuntime,
+};
+use std::{sync::Arc, time::Duration};
+
+/// Retries requests that failed with a retryable error or transient status code.
+///
+/// Each attempt sends a copy of the original request so that per-retry policies start fresh.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    options: RetryOptions,
+    runtime: Arc<dyn AsyncRuntime>,
+}
+
+impl RetryPolicy {
+    /// Create a [`RetryPolicy`] that waits between attempts using `runtime`.
+    pub fn new(options: RetryOptions, runtime: Arc<dyn AsyncRuntime>) -> Self {
+        Self { options, runtime }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for RetryPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let mut attempt = 0u32;
+        loop {
+            let mut attempt_request = request.clone();
+            let result = next[0].send(ctx, &mut attempt_request, &next[1..]).await;
+
+            let retry_after = match &result {
+                Ok(response) if is_transient_status(response.status()) => {
+                    Some(retry_after(response.headers()))
+                }
+                Err(error) if error.is_retryable() => Some(None),
+                _ => None,
+            };
+            let Some(retry_after) = retry_after else {
+                return result;
+            };
+
+            attempt += 1;
+            let Some(delay) = self.options.delay(attempt) else {
+                return result;
+            };
+
+            let delay = retry_after.map_or(delay, |after| after.min(self.options.max_delay()));
+            self.runtime.sleep(delay).await;
+        }
+    }
+}
+
+/// Gets the delay requested by the service from `retry-after-ms`, `x-ms-retry-after-ms`, or `retry-after` headers.
+pub(crate) fn retry_after(headers: &Headers) -> Option<Duration> {
+    for name in [&RETRY_AFTER_MS, &X_MS_RETRY_AFTER_MS] {
+        if let Some(ms) = headers.get_optional_as::<u64, _>(name).ok().flatten() {
+            return Some(Duration::from_millis(ms));
+        }
+    }
+    headers
+        .get_optional_as::<u64, _>(&RETRY_AFTER)
+        .ok()
+        .flatten()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        options::{ExponentialRetryOptions, FixedRetryOptions},
+        runtime::StdRuntime,
+        testing::{RecordRuntime, ResponsePolicy},
+        Url,
+    };
+
+    fn retry_options(max_retries: u32) -> RetryOptions {
+        RetryOptions::fixed(FixedRetryOptions {
+            delay: Duration::ZERO,
+            max_retries,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn retries_transient_status() {
+        let statuses: Arc<dyn Policy> = Arc::new(ResponsePolicy::statuses([503, 429, 200]));
+        let policy = RetryPolicy::new(retry_options(3), Arc::new(StdRuntime));
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let response = policy
+            .send(&mut Context::default(), &mut request, &[statuses])
+            .await
+            .expect("expected response");
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn stops_after_max_retries() {
+        let statuses: Arc<dyn Policy> = Arc::new(ResponsePolicy::statuses([500, 500, 200]));
+        let policy = RetryPolicy::new(retry_options(1), Arc::new(StdRuntime));
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let response = policy
+            .send(&mut Context::default(), &mut request, &[statuses])
+            .await
+            .expect("expected response");
+        assert_eq!(response.status(), 500);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let statuses: Arc<dyn Policy> = Arc::new(ResponsePolicy::statuses([404, 200]));
+        let policy = RetryPolicy::new(retry_options(3), Arc::new(StdRuntime));
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let response = policy
+            .send(&mut Context::default(), &mut request, &[statuses])
+            .await
+            .expect("expected response");
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn sleeps_using_runtime() {
+        let statuses: Arc<dyn Policy> = Arc::new(ResponsePolicy::statuses([503, 503, 200]));
+        let runtime = Arc::new(RecordRuntime::default());
+        let policy = RetryPolicy::new(
+            RetryOptions::fixed(FixedRetryOptions {
+                delay: Duration::from_secs(30),
+                max_retries: 3,
+                ..Default::default()
+            }),
+            runtime.clone(),
+        );
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let response = policy
+            .send(&mut Context::default(), &mut request, &[statuses])
+            .await
+            .expect("expected response");
+        assert_eq!(response.status(), 200);
+        assert_eq!(runtime.sleeps(), [Duration::from_secs(30); 2]);
+    }
+
+    #[tokio::test]
+    async fn caps_retry_after_at_max_delay() {
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "86400");
+        let throttled: Arc<dyn Policy> =
+            Arc::new(ResponsePolicy::new([(429, headers), (200, Headers::new())]));
+        let runtime = Arc::new(RecordRuntime::default());
+        let policy = RetryPolicy::new(
+            RetryOptions::exponential(ExponentialRetryOptions {
+                max_delay: Duration::from_secs(10),
+                ..Default::default()
+            }),
+            runtime.clone(),
+        );
+        let mut request = Request::new(Url::parse("https://localhost").unwrap(), "GET");
+
+        let response = policy
+            .send(&mut Context::default(), &mut request, &[throttled])
+            .await
+            .expect("expected response");
+        assert_eq!(response.status(), 200);
+        assert_eq!(runtime.sleeps(), [Duration::from_secs(10)]);
+    }
+
+    #[test]
+    fn retry_after_headers() {
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "2");
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert(RETRY_AFTER_MS, "150");
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(150)));
+    }
+}
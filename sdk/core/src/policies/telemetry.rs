@@ -0,0 +1,41 @@
+use crate::{
+    context::Context,
+    headers::USER_AGENT,
+    policies::{Policy, PolicyResult},
+    request::Request,
+};
+use std::sync::Arc;
+
+/// Sets the `user-agent` header to identify the client library and platform.
+#[derive(Clone, Debug)]
+pub struct TelemetryPolicy {
+    header: String,
+}
+
+impl TelemetryPolicy {
+    pub fn new(crate_name: Option<&'static str>, crate_version: Option<&'static str>) -> Self {
+        // Library names conventionally omit the "azure_" prefix e.g., "azsdk-rust-security_keyvault/0.1.0".
+        let name = crate_name.unwrap_or("unknown");
+        let name = name.strip_prefix("azure_").unwrap_or(name);
+        let version = crate_version.unwrap_or("unknown");
+        let header = format!(
+            "azsdk-rust-{name}/{version} ({}-{})",
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+        );
+        Self { header }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for TelemetryPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        request.insert_header(USER_AGENT, self.header.clone());
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -0,0 +1,533 @@
+use crate::{
+    cancellation::cancellable,
+    context::Context,
+    error::{Error, ErrorKind},
+    headers::{AZURE_ASYNCOPERATION, LOCATION, OPERATION_LOCATION},
+    pipeline::Pipeline,
+    policies::retry_after,
+    request::Request,
+    response::Response,
+    Url,
+};
+use bytes::Bytes;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{marker::PhantomData, str::FromStr, time::Duration};
+
+/// The default interval between polls if the service does not return a `retry-after` header.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The default maximum interval between polls, including any `retry-after` returned by the service.
+pub const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The status of a long-running operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PollerStatus {
+    NotStarted,
+    InProgress,
+    Succeeded,
+    Failed,
+    Canceled,
+}
+
+impl PollerStatus {
+    /// Whether the operation has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            PollerStatus::Succeeded | PollerStatus::Failed | PollerStatus::Canceled
+        )
+    }
+}
+
+impl From<&str> for PollerStatus {
+    /// Parse a status returned by the service; any unrecognized status is considered [`PollerStatus::InProgress`].
+    fn from(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "notstarted" => PollerStatus::NotStarted,
+            "succeeded" => PollerStatus::Succeeded,
+            "failed" => PollerStatus::Failed,
+            "canceled" | "cancelled" => PollerStatus::Canceled,
+            _ => PollerStatus::InProgress,
+        }
+    }
+}
+
+impl FromStr for PollerStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+/// How the status of a long-running operation is polled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum PollingMethod {
+    /// Poll the `operation-location` URL for a status monitor.
+    OperationLocation,
+    /// Poll the `azure-asyncoperation` URL for a status monitor.
+    AzureAsyncOperation,
+    /// Poll the `location` URL until it no longer returns 202.
+    Location,
+}
+
+/// A token to resume polling a long-running operation, even in another process.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeToken {
+    method: PollingMethod,
+    polling_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    final_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StatusMonitor {
+    status: String,
+    error: Option<StatusMonitorError>,
+    #[serde(rename = "resourceLocation")]
+    resource_location: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StatusMonitorError {
+    code: Option<String>,
+    message: Option<String>,
+}
+
+/// Polls a long-running operation until completion.
+///
+/// Supports polling via `operation-location`, `azure-asyncoperation`, or `location` headers
+/// and honors any `retry-after` returned by the service between polls.
+/// Insert a [`CancellationToken`](crate::CancellationToken) into the [`Context`] to cancel waiting.
+#[derive(Debug)]
+pub struct Poller<T> {
+    pipeline: Pipeline,
+    ctx: Context,
+    state: Option<ResumeToken>,
+    status: PollerStatus,
+    error: Option<String>,
+    final_body: Option<Bytes>,
+    retry_after: Option<Duration>,
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Poller<T>
+where
+    T: DeserializeOwned,
+{
+    /// Create a [`Poller`] from the initial `request` and `response` that started a long-running operation.
+    ///
+    /// If the response does not contain a polling header, the operation is considered complete
+    /// and the response body is the final result.
+    pub async fn new(
+        pipeline: Pipeline,
+        ctx: Context,
+        request: &Request,
+        response: Response,
+    ) -> crate::Result<Self> {
+        let headers = response.headers();
+        let location = headers.get_optional_string(&LOCATION);
+        let (method, polling_url) =
+            if let Some(url) = headers.get_optional_string(&OPERATION_LOCATION) {
+                (PollingMethod::OperationLocation, url)
+            } else if let Some(url) = headers.get_optional_string(&AZURE_ASYNCOPERATION) {
+                (PollingMethod::AzureAsyncOperation, url)
+            } else if let (Some(url), 202) = (&location, response.status()) {
+                (PollingMethod::Location, url.clone())
+            } else {
+                let body = response.into_body().collect().await?;
+                let mut poller = Self::with_state(pipeline, ctx, None);
+                poller.status = PollerStatus::Succeeded;
+                poller.final_body = Some(body);
+                return Ok(poller);
+            };
+
+        // Status monitors do not return the resource, so it must be fetched when the operation succeeds.
+        let final_url = match method {
+            PollingMethod::Location => None,
+            _ if matches!(request.method(), "PUT" | "PATCH") => Some(request.url().to_string()),
+            _ => location,
+        };
+
+        let mut poller = Self::with_state(
+            pipeline,
+            ctx,
+            Some(ResumeToken {
+                method,
+                polling_url,
+                final_url,
+            }),
+        );
+        poller.retry_after = retry_after(response.headers());
+        Ok(poller)
+    }
+
+    /// Resume polling a long-running operation from a [`ResumeToken`].
+    pub fn from_resume_token(pipeline: Pipeline, ctx: Context, resume_token: ResumeToken) -> Self {
+        Self::with_state(pipeline, ctx, Some(resume_token))
+    }
+
+    fn with_state(pipeline: Pipeline, ctx: Context, state: Option<ResumeToken>) -> Self {
+        Self {
+            pipeline,
+            ctx,
+            state,
+            status: PollerStatus::InProgress,
+            error: None,
+            final_body: None,
+            retry_after: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_poll_interval: DEFAULT_MAX_POLL_INTERVAL,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets the last known status of the operation.
+    pub fn status(&self) -> &PollerStatus {
+        &self.status
+    }
+
+    /// Gets a [`ResumeToken`] to resume polling, or `None` if the operation completed without polling.
+    pub fn resume_token(&self) -> Option<ResumeToken> {
+        self.state.clone()
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// Set the interval between polls if the service does not return a `retry-after` header.
+    pub fn set_poll_interval(&mut self, poll_interval: Duration) {
+        self.poll_interval = poll_interval;
+    }
+
+    pub fn max_poll_interval(&self) -> Duration {
+        self.max_poll_interval
+    }
+
+    /// Set the maximum interval between polls, which caps any `retry-after` returned by the service.
+    pub fn set_max_poll_interval(&mut self, max_poll_interval: Duration) {
+        self.max_poll_interval = max_poll_interval;
+    }
+
+    /// Poll the status of the operation once without waiting.
+    pub async fn poll(&mut self) -> crate::Result<&PollerStatus> {
+        let Some(state) = self.state.as_mut() else {
+            return Ok(&self.status);
+        };
+        if self.status.is_terminal() {
+            return Ok(&self.status);
+        }
+
+        let mut request = Request::new(Url::parse(&state.polling_url)?, "GET");
+        let mut ctx = self.ctx.clone();
+        let response = self.pipeline.send(&mut ctx, &mut request).await?;
+        self.retry_after = retry_after(response.headers());
+
+        match state.method {
+            PollingMethod::Location => {
+                if response.status() == 202 {
+                    if let Some(url) = response.headers().get_optional_string(&LOCATION) {
+                        state.polling_url = url;
+                    }
+                    self.status = PollerStatus::InProgress;
+                } else {
+                    self.final_body = Some(response.into_body().collect().await?);
+                    self.status = PollerStatus::Succeeded;
+                }
+            }
+            PollingMethod::OperationLocation | PollingMethod::AzureAsyncOperation => {
+                let monitor: StatusMonitor = response.json().await?;
+                self.status = monitor.status.as_str().into();
+                if let Some(url) = monitor.resource_location {
+                    state.final_url = Some(url);
+                }
+                if let Some(error) = monitor.error {
+                    self.error = Some(format!(
+                        "{}: {}",
+                        error.code.as_deref().unwrap_or("unknown"),
+                        error.message.as_deref().unwrap_or_default()
+                    ));
+                }
+            }
+        }
+
+        Ok(&self.status)
+    }
+
+    /// Wait for the operation to complete and return the final result.
+    ///
+    /// Returns an [`ErrorKind::Cancelled`] error if the [`CancellationToken`](crate::CancellationToken)
+    /// in the [`Context`] is cancelled, or if the service reports the operation was canceled.
+    pub async fn wait(&mut self) -> crate::Result<T> {
+        loop {
+            match self.status {
+                PollerStatus::Succeeded => return self.result().await,
+                PollerStatus::Failed => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "long-running operation failed: {}",
+                            self.error.as_deref().unwrap_or("unknown")
+                        )
+                    }))
+                }
+                PollerStatus::Canceled => {
+                    return Err(Error::message(
+                        ErrorKind::Cancelled,
+                        "long-running operation was canceled",
+                    ))
+                }
+                _ => {}
+            }
+
+            let delay = self.retry_after.map_or(self.poll_interval, |after| {
+                after.min(self.max_poll_interval)
+            });
+            let sleep = self.pipeline.runtime().sleep(delay);
+            cancellable(&self.ctx, async {
+                sleep.await;
+                Ok(())
+            })
+            .await?;
+
+            let ctx = self.ctx.clone();
+            cancellable(&ctx, self.poll()).await?;
+        }
+    }
+
+    async fn result(&self) -> crate::Result<T> {
+        let body = match (
+            &self.final_body,
+            self.state.as_ref().and_then(|s| s.final_url.as_ref()),
+        ) {
+            (Some(body), _) => body.clone(),
+            (None, Some(url)) => {
+                let mut request = Request::new(Url::parse(url)?, "GET");
+                let mut ctx = self.ctx.clone();
+                let response = self.pipeline.send(&mut ctx, &mut request).await?;
+                response.into_body().collect().await?
+            }
+            (None, None) => Bytes::new(),
+        };
+
+        // Operations without a result e.g., deletes, may return an empty body.
+        match body.is_empty() {
+            true => crate::json::from_json("null"),
+            false => crate::json::from_json(body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        headers::{Headers, RETRY_AFTER},
+        policies::{Policy, PolicyResult},
+        stream::BytesStream,
+        CancellationToken, ClientOptions, HeaderName,
+    };
+    use serde::Deserialize;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Default)]
+    struct OperationPolicy {
+        polls: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for OperationPolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut headers = Headers::new();
+            let (status, body) = match request.url().path() {
+                "/operations/1" => match self.polls.fetch_add(1, Ordering::SeqCst) {
+                    0 => (200, r#"{"status":"Running"}"#),
+                    _ => (200, r#"{"status":"Succeeded"}"#),
+                },
+                "/operations/2" => (
+                    200,
+                    r#"{"status":"Failed","error":{"code":"Conflict","message":"Already exists"}}"#,
+                ),
+                "/status" => match self.polls.fetch_add(1, Ordering::SeqCst) {
+                    0 => {
+                        headers.insert(LOCATION, "https://localhost/status");
+                        (202, "")
+                    }
+                    _ => (200, r#"{"name":"foo"}"#),
+                },
+                "/resource" => (200, r#"{"name":"foo"}"#),
+                path => panic!("unexpected path {path}"),
+            };
+            Ok(Response::new(
+                status,
+                headers,
+                Box::pin(BytesStream::new(body)),
+            ))
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Resource {
+        name: String,
+    }
+
+    fn pipeline() -> Pipeline {
+        Pipeline::new(
+            None,
+            None,
+            &ClientOptions::default(),
+            vec![Arc::new(OperationPolicy::default())],
+            Vec::new(),
+        )
+    }
+
+    fn accepted(name: HeaderName, url: &'static str) -> Response {
+        let mut headers = Headers::new();
+        headers.insert(name, url);
+        Response::new(202, headers, Box::pin(BytesStream::new("")))
+    }
+
+    #[tokio::test]
+    async fn operation_location() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let response = accepted(OPERATION_LOCATION, "https://localhost/operations/1");
+        let mut poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+        poller.set_poll_interval(Duration::ZERO);
+
+        assert_eq!(
+            poller.poll().await.expect("status"),
+            &PollerStatus::InProgress
+        );
+        let resource = poller.wait().await.expect("resource");
+        assert_eq!(poller.status(), &PollerStatus::Succeeded);
+        assert_eq!(resource.name, "foo");
+    }
+
+    #[tokio::test]
+    async fn location() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "POST");
+        let response = accepted(LOCATION, "https://localhost/status");
+        let mut poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+        poller.set_poll_interval(Duration::ZERO);
+
+        let resource = poller.wait().await.expect("resource");
+        assert_eq!(resource.name, "foo");
+    }
+
+    #[tokio::test]
+    async fn failed() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let response = accepted(AZURE_ASYNCOPERATION, "https://localhost/operations/2");
+        let mut poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+        poller.set_poll_interval(Duration::ZERO);
+
+        let error = poller.wait().await.expect_err("failed");
+        assert_eq!(error.kind(), &ErrorKind::Other);
+        assert_eq!(
+            error.to_string(),
+            "long-running operation failed: Conflict: Already exists"
+        );
+    }
+
+    #[tokio::test]
+    async fn caps_retry_after() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let mut headers = Headers::new();
+        headers.insert(OPERATION_LOCATION, "https://localhost/operations/1");
+        headers.insert(RETRY_AFTER, "86400");
+        let response = Response::new(202, headers, Box::pin(BytesStream::new("")));
+        let mut poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+        poller.set_poll_interval(Duration::ZERO);
+        poller.set_max_poll_interval(Duration::ZERO);
+
+        let resource = tokio::time::timeout(Duration::from_secs(5), poller.wait())
+            .await
+            .expect("retry-after capped")
+            .expect("resource");
+        assert_eq!(resource.name, "foo");
+    }
+
+    #[tokio::test]
+    async fn cancelled() {
+        let token = CancellationToken::new();
+        let mut ctx = Context::default();
+        ctx.insert(token.clone());
+
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let response = accepted(OPERATION_LOCATION, "https://localhost/operations/1");
+        let mut poller: Poller<Resource> = Poller::new(pipeline(), ctx, &request, response)
+            .await
+            .expect("poller");
+        poller.set_poll_interval(Duration::from_secs(60));
+
+        let cancel = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            token.cancel();
+        };
+        let (result, _) = tokio::join!(poller.wait(), cancel);
+        assert_eq!(result.expect_err("cancelled").kind(), &ErrorKind::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn resume_token() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let response = accepted(OPERATION_LOCATION, "https://localhost/operations/1");
+        let poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+
+        let token = serde_json::to_string(&poller.resume_token()).expect("serialize");
+        assert_eq!(
+            token,
+            r#"{"method":"operationLocation","pollingUrl":"https://localhost/operations/1","finalUrl":"https://localhost/resource"}"#
+        );
+
+        let token: ResumeToken = serde_json::from_str(&token).expect("deserialize");
+        let mut poller: Poller<Resource> =
+            Poller::from_resume_token(pipeline(), Context::default(), token);
+        poller.set_poll_interval(Duration::ZERO);
+        assert_eq!(poller.wait().await.expect("resource").name, "foo");
+    }
+
+    #[tokio::test]
+    async fn completed_without_polling() {
+        let request = Request::new(Url::parse("https://localhost/resource").unwrap(), "PUT");
+        let response = Response::new(
+            200,
+            Headers::new(),
+            Box::pin(BytesStream::new(r#"{"name":"foo"}"#)),
+        );
+        let mut poller: Poller<Resource> =
+            Poller::new(pipeline(), Context::default(), &request, response)
+                .await
+                .expect("poller");
+
+        assert_eq!(poller.resume_token(), None);
+        assert_eq!(poller.wait().await.expect("resource").name, "foo");
+    }
+}
@@ -2,7 +2,7 @@ use bytes::Bytes;
 use serde::Serialize;
 use url::Url;
 
-use crate::{AsHeaders, Headers};
+use crate::{headers::CONTENT_TYPE, AsHeaders, Headers, JsonPatch};
 
 #[derive(Clone, Debug)]
 pub enum Body {
@@ -77,9 +77,18 @@ impl Request {
         self.headers.insert(key, value);
     }
 
+    pub fn append_header<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<crate::headers::HeaderName>,
+        V: Into<crate::headers::HeaderValue>,
+    {
+        self.headers.append(key, value);
+    }
+
+    /// Appends headers, keeping any existing values and every value for the same header name.
     pub fn insert_headers<T: AsHeaders>(&mut self, headers: &T) {
         for (name, value) in headers.as_headers() {
-            self.insert_header(name, value)
+            self.append_header(name, value)
         }
     }
 
@@ -98,4 +107,32 @@ impl Request {
         self.set_body(crate::json::to_json(data)?);
         Ok(())
     }
+
+    /// Set a JSON Merge Patch (RFC 7396) body with content type `application/merge-patch+json`.
+    ///
+    /// Use [`Patch`](crate::Patch) for fields that may be omitted or explicitly set to `null`.
+    pub fn set_merge_patch<T>(&mut self, data: &T) -> crate::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.set_json(data)?;
+        self.insert_header(CONTENT_TYPE, "application/merge-patch+json");
+        Ok(())
+    }
+
+    /// Set a JSON Patch (RFC 6902) body with content type `application/json-patch+json`.
+    pub fn set_json_patch(&mut self, patch: &JsonPatch) -> crate::Result<()> {
+        self.set_json(patch)?;
+        self.insert_header(CONTENT_TYPE, "application/json-patch+json");
+        Ok(())
+    }
+
+    #[cfg(feature = "xml")]
+    pub fn set_xml<T>(&mut self, data: &T) -> crate::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.set_body(crate::xml::to_xml(data)?);
+        Ok(())
+    }
 }
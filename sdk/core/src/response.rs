@@ -1,4 +1,8 @@
-use crate::headers::Headers;
+use crate::{
+    date::{self, OffsetDateTime},
+    headers::{Headers, ETAG, LAST_MODIFIED},
+    Etag,
+};
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use serde::de::DeserializeOwned;
@@ -29,6 +33,19 @@ impl Response {
         &self.headers
     }
 
+    /// Get the [`Etag`] of the resource, if returned.
+    pub fn etag(&self) -> crate::Result<Option<Etag>> {
+        self.headers.get_optional_as(&ETAG)
+    }
+
+    /// Get the time the resource was last modified, if returned.
+    pub fn last_modified(&self) -> crate::Result<Option<OffsetDateTime>> {
+        self.headers
+            .get_optional_str(&LAST_MODIFIED)
+            .map(date::parse_rfc1123)
+            .transpose()
+    }
+
     pub fn into_body(self) -> ResponseBody {
         self.body
     }
@@ -39,6 +56,14 @@ impl Response {
     {
         self.into_body().json().await
     }
+
+    #[cfg(feature = "xml")]
+    pub async fn xml<T>(self) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.into_body().xml().await
+    }
 }
 
 pub struct ResponseBody(PinnedStream);
@@ -48,6 +73,10 @@ impl ResponseBody {
         Self(stream)
     }
 
+    pub(crate) fn into_stream(self) -> PinnedStream {
+        self.0
+    }
+
     pub async fn collect(mut self) -> crate::Result<Bytes> {
         let mut result = Vec::new();
         while let Some(res) = self.0.next().await {
@@ -57,6 +86,22 @@ impl ResponseBody {
         Ok(result.into())
     }
 
+    /// Collect at most `limit` bytes of the body, discarding the remainder.
+    pub(crate) async fn collect_limited(mut self, limit: usize) -> crate::Result<Bytes> {
+        let mut result = Vec::new();
+        while let Some(res) = self.0.next().await {
+            let bytes = res?;
+            let remaining = limit - result.len();
+            if bytes.len() >= remaining {
+                result.extend(&bytes[..remaining]);
+                break;
+            }
+            result.extend(&bytes);
+        }
+
+        Ok(result.into())
+    }
+
     pub async fn json<T>(self) -> crate::Result<T>
     where
         T: DeserializeOwned,
@@ -64,4 +109,13 @@ impl ResponseBody {
         let body = self.collect().await?;
         crate::json::from_json(body)
     }
+
+    #[cfg(feature = "xml")]
+    pub async fn xml<T>(self) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let body = self.collect().await?;
+        crate::xml::from_xml(body)
+    }
 }
@@ -0,0 +1,113 @@
+use crate::error::{Error, ErrorKind};
+use futures::{channel::oneshot, future::BoxFuture, FutureExt};
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+/// A task started by [`AsyncRuntime::spawn`] that completes when the spawned future completes.
+///
+/// Dropping the task does not cancel the spawned future.
+pub type SpawnedTask = BoxFuture<'static, crate::Result<()>>;
+
+/// Timers and task spawning used by policies and pollers e.g., to wait between retries.
+///
+/// Set a runtime for a client using [`ClientOptionsBuilder::with_runtime`](crate::ClientOptionsBuilder::with_runtime).
+pub trait AsyncRuntime: Send + Sync + Debug {
+    /// Returns a future that completes after `duration`.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Runs a future in the background.
+    fn spawn(&self, future: BoxFuture<'static, ()>) -> SpawnedTask;
+}
+
+/// An [`AsyncRuntime`] that works with any executor, and the default unless the `tokio` feature is enabled.
+///
+/// Sleeps use [`futures_timer`]. Each spawned future runs on a new OS thread using [`futures::executor::block_on`],
+/// so this runtime is meant for occasional, runtime-agnostic futures: it does not scale to many tasks,
+/// and futures that need a specific reactor e.g., requests sent by the `reqwest` transport, will fail or panic.
+/// The `reqwest` feature enables the `tokio` feature, which makes [`TokioRuntime`] the default.
+#[derive(Clone, Debug, Default)]
+pub struct StdRuntime;
+
+impl AsyncRuntime for StdRuntime {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        futures_timer::Delay::new(duration).boxed()
+    }
+
+    fn spawn(&self, future: BoxFuture<'static, ()>) -> SpawnedTask {
+        let (tx, rx) = oneshot::channel();
+        std::thread::spawn(move || {
+            futures::executor::block_on(future);
+            let _ = tx.send(());
+        });
+        async move {
+            rx.await
+                .map_err(|_| Error::message(ErrorKind::Other, "spawned task panicked"))
+        }
+        .boxed()
+    }
+}
+
+/// An [`AsyncRuntime`] using [`tokio`], and the default when the `tokio` feature is enabled.
+///
+/// Calls must be made within a tokio runtime.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Debug, Default)]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio")]
+impl AsyncRuntime for TokioRuntime {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        tokio::time::sleep(duration).boxed()
+    }
+
+    fn spawn(&self, future: BoxFuture<'static, ()>) -> SpawnedTask {
+        let handle = tokio::spawn(future);
+        async move {
+            handle
+                .await
+                .map_err(|error| Error::new(ErrorKind::Other, error))
+        }
+        .boxed()
+    }
+}
+
+pub(crate) fn default_runtime() -> Arc<dyn AsyncRuntime> {
+    #[cfg(feature = "tokio")]
+    return Arc::new(TokioRuntime);
+
+    #[cfg(not(feature = "tokio"))]
+    Arc::new(StdRuntime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        time::Instant,
+    };
+
+    async fn sleep_and_spawn(runtime: &dyn AsyncRuntime) {
+        let start = Instant::now();
+        runtime.sleep(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let task = {
+            let ran = ran.clone();
+            runtime.spawn(async move { ran.store(true, Ordering::SeqCst) }.boxed())
+        };
+        task.await.expect("task");
+        assert!(ran.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn std_runtime() {
+        futures::executor::block_on(sleep_and_spawn(&StdRuntime));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_runtime() {
+        sleep_and_spawn(&TokioRuntime).await;
+    }
+}
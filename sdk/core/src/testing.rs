@@ -0,0 +1,155 @@
+//! Mocks shared by unit tests.
+
+use crate::{
+    policies::{Policy, PolicyResult},
+    runtime::{AsyncRuntime, SpawnedTask, StdRuntime},
+    stream::BytesStream,
+    ClientMethodOptions, ClientMethodOptionsBuilder, ClientOptions, ClientOptionsBuilder, Context,
+    ContinuationToken, Headers, Pager, Pipeline, Request, Response, Url,
+};
+use futures::{future::BoxFuture, FutureExt};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Returns queued responses in order, ignoring the request.
+#[derive(Debug, Default)]
+pub struct ResponsePolicy(Mutex<Vec<(u16, Headers)>>);
+
+impl ResponsePolicy {
+    pub fn new(responses: impl IntoIterator<Item = (u16, Headers)>) -> Self {
+        Self(Mutex::new(responses.into_iter().collect()))
+    }
+
+    /// Returns responses with the given statuses and no headers.
+    pub fn statuses(statuses: impl IntoIterator<Item = u16>) -> Self {
+        Self::new(statuses.into_iter().map(|status| (status, Headers::new())))
+    }
+
+    /// The number of responses not yet returned.
+    pub fn remaining(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for ResponsePolicy {
+    async fn send(
+        &self,
+        _ctx: &mut Context,
+        _request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let (status, headers) = self.0.lock().unwrap().remove(0);
+        Ok(Response::new(
+            status,
+            headers,
+            Box::pin(BytesStream::new("")),
+        ))
+    }
+}
+
+/// Records the duration of each sleep and returns immediately.
+#[derive(Debug, Default)]
+pub struct RecordRuntime(Mutex<Vec<Duration>>);
+
+impl RecordRuntime {
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl AsyncRuntime for RecordRuntime {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.0.lock().unwrap().push(duration);
+        futures::future::ready(()).boxed()
+    }
+
+    fn spawn(&self, future: BoxFuture<'static, ()>) -> SpawnedTask {
+        StdRuntime.spawn(future)
+    }
+}
+
+#[derive(Default)]
+pub struct TestOptionsBuilder {
+    options: ClientOptions,
+}
+
+impl ClientOptionsBuilder for TestOptionsBuilder {
+    fn options(&self) -> &ClientOptions {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut ClientOptions {
+        &mut self.options
+    }
+}
+
+#[derive(Default)]
+pub struct TestMethodOptionsBuilder {
+    options: ClientMethodOptions,
+}
+
+impl ClientMethodOptionsBuilder for TestMethodOptionsBuilder {
+    fn options(&self) -> &ClientMethodOptions {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut ClientMethodOptions {
+        &mut self.options
+    }
+}
+
+/// Returns three pages of numbers, linked by a next link and then a continuation token.
+#[derive(Debug)]
+pub struct PagesPolicy;
+
+#[async_trait::async_trait]
+impl Policy for PagesPolicy {
+    async fn send(
+        &self,
+        _ctx: &mut Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let body = match request.url().path() {
+            "/items" => r#"{"value":[1,2],"nextLink":"https://localhost/items/2"}"#,
+            "/items/2" => r#"{"value":[3],"continuationToken":"page3"}"#,
+            "/items/3" => r#"{"value":[4,5]}"#,
+            path => panic!("unexpected path {path}"),
+        };
+        Ok(Response::new(
+            200,
+            Headers::new(),
+            Box::pin(BytesStream::new(body)),
+        ))
+    }
+}
+
+/// Creates a [`Pager`] over the items returned by [`PagesPolicy`].
+pub fn pager(continuation_token: Option<ContinuationToken>) -> Pager<i32> {
+    let pipeline = Pipeline::new(
+        None,
+        None,
+        &ClientOptions::default(),
+        vec![Arc::new(PagesPolicy)],
+        Vec::new(),
+    );
+    Pager::new(
+        pipeline,
+        Context::default(),
+        continuation_token,
+        |continuation| {
+            let url = match continuation {
+                None => Url::parse("https://localhost/items")?,
+                Some(ContinuationToken::NextLink(link)) => Url::parse(link)?,
+                Some(ContinuationToken::Token(token)) => {
+                    assert_eq!(token, "page3");
+                    Url::parse("https://localhost/items/3")?
+                }
+            };
+            Ok(Request::new(url, "GET"))
+        },
+    )
+}
@@ -0,0 +1,123 @@
+//! Serialize and deserialize XML used by services like Storage.
+
+use crate::error::{ErrorKind, ResultExt};
+use bytes::Bytes;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+
+const DECLARATION: &str = r#"<?xml version="1.0" encoding="utf-8"?>"#;
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+pub fn to_xml<T>(value: &T) -> crate::Result<Bytes>
+where
+    T: ?Sized + Serialize,
+{
+    let xml = quick_xml::se::to_string(value).with_context(ErrorKind::DataConversion, || {
+        let ty = std::any::type_name::<T>();
+        format!("failed to serialize {ty} into xml")
+    })?;
+    Ok(Bytes::from(format!("{DECLARATION}{xml}")))
+}
+
+/// Deserialize XML, ignoring any leading UTF-8 byte order mark.
+pub fn from_xml<S, T>(body: S) -> crate::Result<T>
+where
+    S: AsRef<[u8]>,
+    T: DeserializeOwned,
+{
+    let body = body.as_ref();
+    let body = body.strip_prefix(UTF8_BOM).unwrap_or(body);
+    let xml = std::str::from_utf8(body).context(ErrorKind::DataConversion, "xml is not UTF-8")?;
+    quick_xml::de::from_str(xml).with_context(ErrorKind::DataConversion, || {
+        let ty = std::any::type_name::<T>();
+        format!("failed to deserialize xml into {ty}")
+    })
+}
+
+/// Deserialize a list of elements wrapped in a parent element.
+///
+/// Storage returns lists like `<Containers><Container/><Container/></Containers>`.
+/// Use this with `#[serde(default, deserialize_with = "azure_core::xml::read_list")]`
+/// on a `Vec<T>` field named for the wrapping element; an empty or missing element yields an empty `Vec`.
+pub fn read_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(bound(deserialize = "T: Deserialize<'de>"))]
+    struct List<T> {
+        #[serde(rename = "$value", default = "Vec::new")]
+        items: Vec<T>,
+    }
+
+    Ok(Option::<List<T>>::deserialize(deserializer)?
+        .map(|list| list.items)
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "PascalCase")]
+    struct EnumerationResults {
+        prefix: Option<String>,
+        #[serde(default, deserialize_with = "read_list")]
+        containers: Vec<Container>,
+        next_marker: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Container {
+        name: String,
+    }
+
+    #[test]
+    fn wrapped_list() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><EnumerationResults ServiceEndpoint=\"https://account.blob.core.windows.net/\"><Prefix>c</Prefix><Containers><Container><Name>c1</Name></Container><Container><Name>c2</Name></Container></Containers><NextMarker /></EnumerationResults>";
+        let results: EnumerationResults = from_xml(xml).expect("deserialize");
+        assert_eq!(results.prefix.as_deref(), Some("c"));
+        assert_eq!(
+            results.containers,
+            [
+                Container {
+                    name: "c1".to_string()
+                },
+                Container {
+                    name: "c2".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_wrapped_list() {
+        let xml = "<EnumerationResults><Containers /></EnumerationResults>";
+        let results: EnumerationResults = from_xml(xml).expect("deserialize");
+        assert!(results.containers.is_empty());
+
+        let xml = "<EnumerationResults></EnumerationResults>";
+        let results: EnumerationResults = from_xml(xml).expect("deserialize");
+        assert!(results.containers.is_empty());
+    }
+
+    #[test]
+    fn serialize() {
+        let xml = to_xml(&Container {
+            name: "c1".to_string(),
+        })
+        .expect("serialize");
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><Container><Name>c1</Name></Container>"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let error = from_xml::<_, Container>("<Container>").expect_err("invalid xml");
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+}
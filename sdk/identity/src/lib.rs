@@ -1,4 +1,86 @@
+use azure_core::ClientOptions;
+
+/// A credential for the cloud set in [`ClientOptions::cloud`].
+///
+/// Tokens are not acquired yet because [`TokenCredential`](azure_core::TokenCredential) has no methods to request them,
+/// so selecting a cloud only changes [`authority_host`](Self::authority_host) until token requests are implemented.
 #[derive(Debug, Default)]
-pub struct DefaultAzureCredential {}
+pub struct DefaultAzureCredential {
+    options: DefaultAzureCredentialOptions,
+}
+
+impl DefaultAzureCredential {
+    pub fn new(options: Option<DefaultAzureCredentialOptions>) -> Self {
+        Self {
+            options: options.unwrap_or_default(),
+        }
+    }
+
+    /// The authority host tokens will be requested from, derived from [`ClientOptions::cloud`].
+    ///
+    /// No token requests are sent yet; see [`DefaultAzureCredential`].
+    pub fn authority_host(&self) -> &str {
+        self.options.client_options.cloud().authority_host()
+    }
+}
 
 impl azure_core::TokenCredential for DefaultAzureCredential {}
+
+#[derive(Clone, Debug, Default)]
+pub struct DefaultAzureCredentialOptions {
+    client_options: ClientOptions,
+}
+
+impl DefaultAzureCredentialOptions {
+    pub fn builder() -> builders::DefaultAzureCredentialOptionsBuilder {
+        builders::DefaultAzureCredentialOptionsBuilder::default()
+    }
+}
+
+pub mod builders {
+    use super::*;
+    use azure_core::ClientOptionsBuilder;
+
+    #[derive(Default)]
+    pub struct DefaultAzureCredentialOptionsBuilder {
+        options: DefaultAzureCredentialOptions,
+    }
+
+    impl DefaultAzureCredentialOptionsBuilder {
+        pub fn build(&self) -> DefaultAzureCredentialOptions {
+            self.options.clone()
+        }
+    }
+
+    impl ClientOptionsBuilder for DefaultAzureCredentialOptionsBuilder {
+        fn options(&self) -> &ClientOptions {
+            &self.options.client_options
+        }
+
+        fn options_mut(&mut self) -> &mut ClientOptions {
+            &mut self.options.client_options
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{cloud::CloudConfiguration, ClientOptionsBuilder};
+
+    #[test]
+    fn authority_host_from_cloud() {
+        assert_eq!(
+            DefaultAzureCredential::default().authority_host(),
+            "https://login.microsoftonline.com/"
+        );
+
+        let options = DefaultAzureCredentialOptions::builder()
+            .with_cloud(CloudConfiguration::AzureChina)
+            .build();
+        assert_eq!(
+            DefaultAzureCredential::new(Some(options)).authority_host(),
+            "https://login.chinacloudapi.cn/"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use azure_core::ClientMethodOptionsBuilder;
+use azure_identity::DefaultAzureCredential;
+use azure_security_keyvault::{blocking::SecretClient, SetSecretOptions};
+use std::{env, sync::Arc};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let endpoint = env::var("AZURE_KEYVAULT_URL")?;
+
+    let credential = Arc::new(DefaultAzureCredential::default());
+    let client = SecretClient::new(endpoint, credential, None)?;
+
+    let options = SetSecretOptions::builder()
+        .with_content_type("text/plain")
+        .with_header("x-ms-client-request-id", "blocking-example")
+        .build();
+    let secret = client.set_secret("secret-name", "secret-value", Some(options))?;
+    println!("set {} version {}", secret.name, secret.version);
+
+    for item in client.list_secrets(None) {
+        println!("{}", item?.id);
+    }
+
+    Ok(())
+}
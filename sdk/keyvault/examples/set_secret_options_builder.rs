@@ -33,7 +33,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let options = SetSecretOptions::builder()
         .with_context(ctx)
-        .with_properties(SecretProperties { enabled: false })
+        .with_properties(SecretProperties {
+            enabled: false,
+            ..Default::default()
+        })
         .build();
     let response = client
         .set_secret("secret-name", "rotated-value", Some(options))
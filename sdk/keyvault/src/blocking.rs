@@ -0,0 +1,57 @@
+//! Synchronous clients that block the current thread until the service responds.
+//!
+//! Options and models are shared with the asynchronous clients.
+//! Methods return deserialized models instead of responses, so no response bodies are read asynchronously.
+
+use crate::{ListSecretsOptions, Secret, SecretClientOptions, SecretItem, SetSecretOptions};
+use azure_core::{
+    blocking::{block_on, Pager},
+    Result, TokenCredential, Url,
+};
+use std::sync::Arc;
+
+/// A synchronous [`SecretClient`](crate::SecretClient).
+#[derive(Debug, Clone)]
+pub struct SecretClient {
+    client: crate::SecretClient,
+}
+
+impl SecretClient {
+    pub fn new(
+        endpoint: impl AsRef<str>,
+        credential: Arc<dyn TokenCredential>,
+        options: Option<SecretClientOptions>,
+    ) -> Result<Self> {
+        Ok(Self {
+            client: crate::SecretClient::new(endpoint, credential, options)?,
+        })
+    }
+
+    pub fn endpoint(&self) -> &Url {
+        self.client.endpoint()
+    }
+
+    /// Sets a secret and returns it, including its new version.
+    pub fn set_secret<N, V>(
+        &self,
+        name: N,
+        value: V,
+        options: Option<SetSecretOptions>,
+    ) -> Result<Secret>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        block_on(async {
+            self.client
+                .set_secret(name, value, options)
+                .await?
+                .json()
+                .await
+        })
+    }
+
+    pub fn list_secrets(&self, options: Option<ListSecretsOptions>) -> Pager<SecretItem> {
+        self.client.list_secrets(options).into()
+    }
+}
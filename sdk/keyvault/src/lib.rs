@@ -1,11 +1,15 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod models;
 
 use azure_core::{
+    cloud,
     policies::{ApiKeyAuthenticationPolicy, Policy},
-    ClientMethodOptions, ClientOptions, Pipeline, Request, Response, Result, Span, TokenCredential,
-    Url,
+    ClientMethodOptions, ClientOptions, ContinuationToken, Error, ErrorKind, Pager, Pipeline,
+    Request, Response, Result, Span, TokenCredential, Url,
 };
 pub use models::*;
+use serde::Deserialize;
 use std::{collections::HashMap, sync::Arc};
 
 #[derive(Debug, Clone)]
@@ -27,10 +31,9 @@ impl SecretClient {
             .clear()
             .append_pair("api-version", &options.api_version);
 
-        let auth_policy: Arc<dyn Policy> = Arc::new(ApiKeyAuthenticationPolicy::new(
-            credential.clone(),
-            "https://vault.azure.net/.default".to_string(),
-        ));
+        let scope = options.client_options.cloud().scope(cloud::KEY_VAULT)?;
+        let auth_policy: Arc<dyn Policy> =
+            Arc::new(ApiKeyAuthenticationPolicy::new(credential.clone(), scope));
         let per_retry_policies = vec![auth_policy];
 
         Ok(Self {
@@ -77,10 +80,56 @@ impl SecretClient {
 
         self.pipeline.send(&mut ctx, &mut request).await
     }
+
+    pub fn list_secrets(&self, options: Option<ListSecretsOptions>) -> Pager<SecretItem> {
+        let options = options.unwrap_or_default();
+
+        let mut ctx = options.client_method_options.context().clone();
+        ctx.insert(Span::from("SecretClient::list_secrets"));
+
+        let endpoint = self.endpoint.clone();
+        Pager::new(
+            self.pipeline.clone(),
+            ctx,
+            options.continuation_token,
+            move |continuation| {
+                let url = match continuation {
+                    None => {
+                        let mut url = endpoint.clone();
+                        url.set_path("secrets");
+                        url
+                    }
+                    Some(ContinuationToken::NextLink(next_link)) => Url::parse(next_link)?,
+                    Some(ContinuationToken::Token(_)) => {
+                        return Err(Error::message(
+                            ErrorKind::DataConversion,
+                            "expected nextLink continuation token",
+                        ))
+                    }
+                };
+                Ok(Request::new(url, "GET"))
+            },
+        )
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Options for [`SecretClient`].
+///
+/// Settings can be loaded from the `keyvault` section of a [`Config`](azure_core::config::Config):
+///
+/// ```
+/// use azure_core::config::Config;
+/// use azure_security_keyvault::SecretClientOptions;
+///
+/// let options: SecretClientOptions = Config::from_json(r#"{"keyvault":{"api_version":"7.4"}}"#)?
+///     .with_env_vars()
+///     .options(Some("keyvault"))?;
+/// # Ok::<(), azure_core::Error>(())
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct SecretClientOptions {
+    #[serde(flatten)]
     client_options: ClientOptions,
     api_version: String,
 }
@@ -91,7 +140,7 @@ impl SecretClientOptions {
     }
 
     pub fn builder() -> builders::SecretClientOptionsBuilder {
-        todo!()
+        builders::SecretClientOptionsBuilder::default()
     }
 }
 
@@ -126,7 +175,23 @@ impl SetSecretOptions {
     }
 
     pub fn builder() -> builders::SetSecretOptionsBuilder {
-        todo!()
+        builders::SetSecretOptionsBuilder::default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ListSecretsOptions {
+    continuation_token: Option<ContinuationToken>,
+    client_method_options: ClientMethodOptions,
+}
+
+impl ListSecretsOptions {
+    pub fn continuation_token(&self) -> Option<&ContinuationToken> {
+        self.continuation_token.as_ref()
+    }
+
+    pub fn builder() -> builders::ListSecretsOptionsBuilder {
+        builders::ListSecretsOptionsBuilder::default()
     }
 }
 
@@ -211,4 +276,37 @@ pub mod builders {
             &mut self.options.client_method_options
         }
     }
+
+    #[derive(Default)]
+    pub struct ListSecretsOptionsBuilder {
+        options: ListSecretsOptions,
+    }
+
+    impl ListSecretsOptionsBuilder {
+        pub fn continuation_token(&self) -> Option<&ContinuationToken> {
+            self.options.continuation_token.as_ref()
+        }
+
+        pub fn with_continuation_token(
+            &mut self,
+            continuation_token: impl Into<ContinuationToken>,
+        ) -> &mut Self {
+            self.options.continuation_token = Some(continuation_token.into());
+            self
+        }
+
+        pub fn build(&self) -> ListSecretsOptions {
+            self.options.clone()
+        }
+    }
+
+    impl ClientMethodOptionsBuilder for ListSecretsOptionsBuilder {
+        fn options(&self) -> &ClientMethodOptions {
+            &self.options.client_method_options
+        }
+
+        fn options_mut(&mut self) -> &mut ClientMethodOptions {
+            &mut self.options.client_method_options
+        }
+    }
 }
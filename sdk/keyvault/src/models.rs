@@ -1,3 +1,4 @@
+use azure_core::date::OffsetDateTime;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,17 +10,56 @@ pub struct Secret {
     pub properties: SecretProperties,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecretItem {
+    pub id: String,
+    #[serde(rename = "attributes")]
+    pub properties: SecretProperties,
+    #[serde(rename = "contentType")]
+    pub content_type: Option<String>,
+    pub tags: Option<HashMap<String, String>>,
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 pub(crate) struct SetSecretRequest {
     pub value: String,
-    #[serde(rename = "attributes")]
+    #[serde(rename = "attributes", skip_serializing_if = "Option::is_none")]
     pub properties: Option<SecretProperties>,
-    #[serde(rename = "contentType")]
+    #[serde(rename = "contentType", skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<HashMap<String, String>>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SecretProperties {
     pub enabled: bool,
+    #[serde(
+        rename = "exp",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::date::unix_time::option"
+    )]
+    pub expires_on: Option<OffsetDateTime>,
+    #[serde(
+        rename = "nbf",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::date::unix_time::option"
+    )]
+    pub not_before: Option<OffsetDateTime>,
+    #[serde(
+        rename = "created",
+        default,
+        skip_serializing,
+        with = "azure_core::date::unix_time::option"
+    )]
+    pub created_on: Option<OffsetDateTime>,
+    #[serde(
+        rename = "updated",
+        default,
+        skip_serializing,
+        with = "azure_core::date::unix_time::option"
+    )]
+    pub updated_on: Option<OffsetDateTime>,
 }